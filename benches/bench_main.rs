@@ -3,10 +3,18 @@ use image::imageops::FilterType;
 use tapciify::{
     prelude::*,
     renderers::{
-        ascii::DEFAULT_ASCII_STRING, background_string::BackgroundStringArtConverter,
-        braille::BrailleArtConverter,
+        ascii::DEFAULT_ASCII_STRING,
+        background_string::{BackgroundStringArtConverter, BackgroundStringArtConverterOptions},
+        blocks::{BlockArtConverter, BlockArtConverterOptions, BlockKind},
+        braille::{BrailleArtConverter, BrailleArtConverterOptions},
+        contour::{ContourArtConverter, ContourArtConverterOptions},
+        edges::{EdgeArtConverter, EdgeArtConverterOptions},
+        emoji::{EmojiArtConverter, EmojiArtConverterOptions},
+        glyph::{GlyphArtConverter, GlyphArtConverterOptions},
+        half_block::{HalfBlockArtConverter, HalfBlockArtConverterOptions},
+        halftone::{HalftoneArtConverter, HalftoneArtConverterOptions},
     },
-    utils::resize::DEFAULT_FONT_RATIO,
+    utils::{dither::Dithering, lightness::LightnessModel, resize::DEFAULT_FONT_RATIO},
 };
 
 fn bench_main(c: &mut Criterion) {
@@ -26,45 +34,128 @@ fn bench_main(c: &mut Criterion) {
 
     c.bench_function("ascii colored display", |b| {
         let options = AsciiArtConverterOptions {
-            colored: true,
+            color_mode: ColorMode::TrueColor,
             ..Default::default()
         };
         b.iter(|| img.ascii_art(&options).unwrap().to_string());
     });
 
+    c.bench_function("ascii dithered", |b| {
+        let options = AsciiArtConverterOptions {
+            dithering: Dithering::FloydSteinberg,
+            ..Default::default()
+        };
+        b.iter(|| img.ascii_art(&options).unwrap());
+    });
+
+    c.bench_function("ascii lab lightness", |b| {
+        let options = AsciiArtConverterOptions {
+            lightness_model: LightnessModel::Lab,
+            ..Default::default()
+        };
+        b.iter(|| img.ascii_art(&options).unwrap());
+    });
+
     c.bench_function("background string", |b| {
+        let options = BackgroundStringArtConverterOptions::default();
         b.iter(|| {
-            img.background_string_art(DEFAULT_ASCII_STRING, false)
+            img.background_string_art(DEFAULT_ASCII_STRING, &options)
                 .unwrap()
         });
     });
 
     c.bench_function("background string display", |b| {
+        let options = BackgroundStringArtConverterOptions::default();
         b.iter(|| {
-            img.background_string_art(DEFAULT_ASCII_STRING, false)
+            img.background_string_art(DEFAULT_ASCII_STRING, &options)
                 .unwrap()
                 .to_string()
         });
     });
 
     c.bench_function("background string colored display", |b| {
+        let options = BackgroundStringArtConverterOptions {
+            color_mode: ColorMode::TrueColor,
+            ..Default::default()
+        };
         b.iter(|| {
-            img.background_string_art(DEFAULT_ASCII_STRING, true)
+            img.background_string_art(DEFAULT_ASCII_STRING, &options)
                 .unwrap()
                 .to_string()
         });
     });
 
     c.bench_function("braille", |b| {
-        b.iter(|| img.braille_art(false).unwrap());
+        let options = BrailleArtConverterOptions::default();
+        b.iter(|| img.braille_art(&options).unwrap());
     });
 
     c.bench_function("braille display", |b| {
-        b.iter(|| img.braille_art(false).unwrap().to_string());
+        let options = BrailleArtConverterOptions::default();
+        b.iter(|| img.braille_art(&options).unwrap().to_string());
     });
 
     c.bench_function("braille colored display", |b| {
-        b.iter(|| img.braille_art(true).unwrap().to_string());
+        let options = BrailleArtConverterOptions {
+            color_mode: ColorMode::TrueColor,
+            ..Default::default()
+        };
+        b.iter(|| img.braille_art(&options).unwrap().to_string());
+    });
+
+    c.bench_function("braille dithered", |b| {
+        let options = BrailleArtConverterOptions {
+            dithering: Dithering::BlueNoise,
+            ..Default::default()
+        };
+        b.iter(|| img.braille_art(&options).unwrap());
+    });
+
+    c.bench_function("contour", |b| {
+        let options = ContourArtConverterOptions::default();
+        b.iter(|| img.contour_art(&options).unwrap());
+    });
+
+    c.bench_function("edges", |b| {
+        let options = EdgeArtConverterOptions::default();
+        b.iter(|| img.edge_art(&options).unwrap());
+    });
+
+    c.bench_function("emoji", |b| {
+        let options = EmojiArtConverterOptions::default();
+        b.iter(|| img.emoji_art(&options).unwrap());
+    });
+
+    c.bench_function("glyph", |b| {
+        let options = GlyphArtConverterOptions::default();
+        b.iter(|| img.glyph_art(&options).unwrap());
+    });
+
+    c.bench_function("half block", |b| {
+        let options = HalfBlockArtConverterOptions::default();
+        b.iter(|| img.half_block_art(&options).unwrap());
+    });
+
+    c.bench_function("halftone", |b| {
+        let options = HalftoneArtConverterOptions::default();
+        b.iter(|| img.halftone_art(&options).unwrap());
+    });
+
+    c.bench_function("half block colored display", |b| {
+        let options = HalfBlockArtConverterOptions {
+            color_mode: ColorMode::TrueColor,
+            ..Default::default()
+        };
+        b.iter(|| img.half_block_art(&options).unwrap().to_string());
+    });
+
+    c.bench_function("sextant colored display", |b| {
+        let options = BlockArtConverterOptions {
+            kind: BlockKind::Sextant,
+            color_mode: ColorMode::TrueColor,
+            ..Default::default()
+        };
+        b.iter(|| img.block_art(&options).unwrap().to_string());
     });
 }
 
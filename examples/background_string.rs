@@ -1,8 +1,11 @@
-use std::error::Error;
 use image::imageops::FilterType;
+use std::error::Error;
 
 use tapciify::{
-    prelude::*, renderers::background_string::BackgroundStringArtConverter,
+    prelude::*,
+    renderers::background_string::{
+        BackgroundStringArtConverter, BackgroundStringArtConverterOptions,
+    },
     utils::resize::DEFAULT_FONT_RATIO,
 };
 
@@ -11,7 +14,10 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let result = img
         .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
-        .background_string_art("hello world! ", false)?;
+        .background_string_art(
+            "hello world! ",
+            &BackgroundStringArtConverterOptions::default(),
+        )?;
 
     println!("{}", result);
 
@@ -1,9 +1,11 @@
-use std::error::Error;
 use image::imageops::FilterType;
+use std::error::Error;
 
 use tapciify::{
     prelude::*,
-    renderers::braille::{BrailleArtConverter, DEFAULT_BRAILLE_FONT_RATIO},
+    renderers::braille::{
+        BrailleArtConverter, BrailleArtConverterOptions, DEFAULT_BRAILLE_FONT_RATIO,
+    },
 };
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -17,7 +19,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             FilterType::Triangle,
         )
         .to_luma8()
-        .braille_art(false)?;
+        .braille_art(&BrailleArtConverterOptions::default())?;
 
     println!("{}", result);
 
@@ -1,5 +1,5 @@
-use std::error::Error;
 use image::imageops::FilterType;
+use std::error::Error;
 
 use tapciify::{prelude::*, utils::resize::DEFAULT_FONT_RATIO};
 
@@ -10,7 +10,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
         .ascii_art(&AsciiArtConverterOptions {
             // Put your other options here
-            colored: true,
+            color_mode: ColorMode::TrueColor,
             ..Default::default()
         })?;
 
@@ -1,5 +1,5 @@
-use std::error::Error;
 use image::imageops::FilterType;
+use std::error::Error;
 
 use tapciify::{prelude::*, utils::resize::DEFAULT_FONT_RATIO};
 
@@ -1,6 +1,6 @@
-use std::error::Error;
 use image::imageops::FilterType;
 use imageproc::contrast::adaptive_threshold;
+use std::error::Error;
 
 use tapciify::{prelude::*, utils::resize::DEFAULT_FONT_RATIO};
 
@@ -1,7 +1,8 @@
 //! Utils used in tapciify CLI
 
+use std::path::PathBuf;
 #[cfg(target_family = "windows")]
-use std::{error, fmt, path::PathBuf};
+use std::{error, fmt};
 
 use clap::Parser;
 #[cfg(target_family = "windows")]
@@ -10,14 +11,35 @@ use glob::glob;
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
 
-use crate::renderers::ascii::DEFAULT_ASCII_STRING;
+use crate::{
+    renderers::{
+        ascii::DEFAULT_ASCII_STRING,
+        blocks::BlockKind,
+        contour::{DEFAULT_CONTOUR_LEVELS, LineStyle},
+        edges::DEFAULT_EDGE_THRESHOLD,
+        emoji::EmojiPalette,
+        halftone::{DEFAULT_HALFTONE_DOTS, DEFAULT_SCREEN_ANGLE, DEFAULT_SCREEN_PERIOD},
+    },
+    utils::{
+        alpha::AlphaMode,
+        color::{CellColor, ColorMode, ColorStyle},
+        dither::Dithering,
+        lightness::LightnessModel,
+        player::OutputMode,
+        preprocess::Adjustment,
+        threshold::ThresholdMode,
+        tone::{ToneMapping, ToneScope},
+        transform::{CropRect, Rotation, Zoom},
+        trim::TrimScope,
+    },
+};
 
 /// Parse command arguments for tapciify CLI
 #[derive(Parser, Debug, Default, Clone, PartialEq)]
 #[clap(author, version, about, long_about = None)]
 pub struct Cli {
     /// Input files to convert to ASCII art
-    #[clap(short, long, num_args = 1.., required=true)]
+    #[clap(short, long, num_args = 1.., required_unless_present = "list_modes")]
     pub input: Vec<String>,
     /// Width of output
     #[clap(short, long)]
@@ -39,6 +61,12 @@ pub struct Cli {
     /// Makes frames colorful
     #[clap(short, long, action)]
     pub colored: bool,
+    /// Color depth of frames, overrides --colored
+    #[clap(long, value_enum)]
+    pub color_mode: Option<ColorMode>,
+    /// Paint characters, their background or both
+    #[clap(long, value_enum, default_value_t = ColorStyle::Foreground)]
+    pub color_style: ColorStyle,
     /// String to represent lightness of pixels
     #[clap(short, long, default_value_t = DEFAULT_ASCII_STRING.to_owned())]
     pub ascii_string: String,
@@ -48,18 +76,164 @@ pub struct Cli {
     /// Reverse the ASCII string
     #[clap(short, long, action)]
     pub reverse: bool,
+    /// Dithering for smoother gradients of ASCII string, braille and background string
+    #[clap(long, value_enum, default_value_t = Dithering::None)]
+    pub dithering: Dithering,
+    /// Way of calculating lightness of pixels
+    #[clap(long, value_enum, default_value_t = LightnessModel::Rec709)]
+    pub lightness: LightnessModel,
     /// Font ratio: width / height
     #[clap(long = "ratio")]
     pub font_ratio: Option<f64>,
+    /// Keep only rectangle of images: `<width>x<height>+<x>+<y>`, in pixels or percents,
+    /// like `50%x50%+25%+0`
+    #[clap(long)]
+    pub crop: Option<CropRect>,
+    /// Zoom into region of images: `<factor>` for center or `<factor>@<x>,<y>`, like `2@30%,40%`
+    #[clap(long)]
+    pub zoom: Option<Zoom>,
+    /// Rotate images clockwise by degrees
+    #[clap(long, value_enum, default_value_t = Rotation::None)]
+    pub rotate: Rotation,
+    /// Mirror images from left to right
+    #[clap(long, action)]
+    pub flip_horizontal: bool,
+    /// Mirror images from top to bottom
+    #[clap(long, action)]
+    pub flip_vertical: bool,
+    /// Remove uniform borders of images, with optional tolerance (from 0.0 to 1.0) of their color
+    #[clap(long, num_args = 0..=1, value_name = "TOLERANCE")]
+    pub trim: Option<Option<f32>>,
+    /// Find borders of every frame or once for all frames, so animations don't jitter
+    #[clap(long, value_enum, default_value_t = TrimScope::Frame)]
+    pub trim_scope: TrimScope,
+    /// Adjust images before resizing, in order: `brightness=<-1.0..1.0>`, `contrast=<factor>`,
+    /// `gamma=<gamma>`, `saturation=<factor>`, `sharpen=<sigma>`, `blur=<sigma>`, `invert`
+    /// or `posterize=<levels>`
+    #[clap(long = "adjust", value_name = "ADJUSTMENT")]
+    pub adjustments: Vec<Adjustment>,
+    /// Stretch lightness of images, so ASCII string covers the full range
+    #[clap(long, value_enum, default_value_t = ToneMapping::None)]
+    pub tone: ToneMapping,
+    /// Build tone curve for every frame or once for all frames, so brightness doesn't pump
+    #[clap(long, value_enum, default_value_t = ToneScope::Frame)]
+    pub tone_scope: ToneScope,
     /// Use threshold for images
     #[clap(short, long)]
     pub threshold: Option<u32>,
+    /// Threshold for braille and background string: `otsu`, `local-mean` or number from 0.0 to 1.0
+    #[clap(long, default_value = "0.5")]
+    pub binary_threshold: ThresholdMode,
+    /// Way of picking color of braille, block and glyph characters from their pixels
+    #[clap(long, value_enum, default_value_t = CellColor::MeanLit)]
+    pub cell_color: CellColor,
+    /// Way of showing transparent pixels: `darken`, `checkerboard`, `transparent` or
+    /// `composite=<hex color>`
+    #[clap(long, default_value = "darken")]
+    pub alpha: AlphaMode,
+    /// Renderer of images, overrides --braille, --half-block, --blocks and --background-string
+    #[clap(short, long, value_enum)]
+    pub mode: Option<Mode>,
+    /// List renderers and exit
+    #[clap(long, action)]
+    pub list_modes: bool,
     /// Use braille pattern for rendering images
     #[clap(short, long, action)]
     pub braille: bool,
+    /// Use half blocks for rendering images, two pixels per character
+    #[clap(long, action)]
+    pub half_block: bool,
+    /// Use quadrant or sextant blocks for rendering images
+    #[clap(long, value_enum)]
+    pub blocks: Option<BlockKind>,
     /// Use text for background on light pixels
     #[clap(long)]
     pub background_string: Option<String>,
+    /// Count of lightness levels, separated by isolines in contour mode
+    #[clap(long, default_value_t = DEFAULT_CONTOUR_LEVELS)]
+    pub contour_levels: u32,
+    /// Style of isolines in contour mode
+    #[clap(long, value_enum, default_value_t = LineStyle::Sharp)]
+    pub line_style: LineStyle,
+    /// Paint isolines by their level in contour mode
+    #[clap(long, action)]
+    pub level_colors: bool,
+    /// Strength of gradient (from 0.0 to 1.0) to draw edge in edges mode
+    #[clap(long, default_value_t = DEFAULT_EDGE_THRESHOLD)]
+    pub edge_threshold: f32,
+    /// Emoji in emoji mode: `squares`, `circles` or comma separated list of `<emoji>=<hex color>`
+    #[clap(long, default_value = "squares")]
+    pub emoji: EmojiPalette,
+    /// Characters to pick by shape in glyph mode, ASCII string is used by default
+    #[clap(long)]
+    pub glyphs: Option<String>,
+    /// TrueType or OpenType font to rasterise glyphs with, built-in bitmap font is used by default
+    #[clap(long)]
+    pub font: Option<PathBuf>,
+    /// Dot glyphs in halftone mode, from the smallest to the largest
+    #[clap(long, default_value_t = DEFAULT_HALFTONE_DOTS.to_owned())]
+    pub dots: String,
+    /// Angle of halftone screen in degrees
+    #[clap(long, default_value_t = DEFAULT_SCREEN_ANGLE)]
+    pub screen_angle: f32,
+    /// Distance between dots of halftone screen in characters
+    #[clap(long, default_value_t = DEFAULT_SCREEN_PERIOD)]
+    pub screen_period: f32,
+    /// Separate colors into cyan, magenta, yellow and key halftone screens
+    #[clap(long, action)]
+    pub cmyk: bool,
+    /// Show images as text or using terminal graphics protocol
+    #[clap(long, value_enum, default_value_t = OutputMode::Text)]
+    pub output: OutputMode,
+}
+
+impl Cli {
+    /// Renderer picked by --mode or by legacy flags
+    pub fn render_mode(&self) -> Mode {
+        match (
+            self.mode,
+            self.background_string.is_some(),
+            self.braille,
+            self.half_block,
+            self.blocks,
+        ) {
+            (Some(mode), _, _, _, _) => mode,
+            (None, true, _, _, _) => Mode::BackgroundString,
+            (None, false, true, _, _) => Mode::Braille,
+            (None, false, false, true, _) => Mode::HalfBlock,
+            (None, false, false, false, Some(BlockKind::Quadrant)) => Mode::Quadrant,
+            (None, false, false, false, Some(BlockKind::Sextant)) => Mode::Sextant,
+            (None, false, false, false, None) => Mode::Ascii,
+        }
+    }
+}
+
+/// Renderers available in tapciify CLI
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Mode {
+    /// Characters of ASCII string picked by lightness
+    #[default]
+    Ascii,
+    /// Braille patterns, 2x4 dots per character
+    Braille,
+    /// Text of --background-string (or ASCII string) on light pixels
+    BackgroundString,
+    /// Half blocks, two pixels per character
+    HalfBlock,
+    /// Quadrant blocks, 2x2 pixels per character
+    Quadrant,
+    /// Sextant blocks, 2x3 pixels per character
+    Sextant,
+    /// Isolines of lightness drawn with box-drawing characters
+    Contour,
+    /// Edges drawn with | / - \ _ by their direction, ASCII string elsewhere
+    Edges,
+    /// Emoji with the closest colors, two columns per pixel
+    Emoji,
+    /// Characters with the most similar shape, 4x8 pixels per character
+    Glyph,
+    /// Dots of rotated print screen, growing with lightness
+    Halftone,
 }
 
 /// Add glob support for paths parsing on Windows
@@ -76,7 +76,7 @@
 //!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
 //!     .ascii_art(&AsciiArtConverterOptions {
 //!         // Put your other options here
-//!         colored: true,
+//!         color_mode: ColorMode::TrueColor,
 //!         ..Default::default()
 //!     })?;
 //!
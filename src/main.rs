@@ -4,11 +4,27 @@ use std::path::PathBuf;
 use clap::{CommandFactory, Parser, error::ErrorKind};
 
 use tapciify::{
-    cli::Cli,
-    renderers::braille::DEFAULT_BRAILLE_FONT_RATIO,
+    cli::{Cli, Mode},
+    prelude::*,
+    renderers::{
+        background_string::BackgroundStringArtConverterOptions,
+        blocks::{BlockArtConverterOptions, BlockKind},
+        braille::BrailleArtConverterOptions,
+        contour::ContourArtConverterOptions,
+        edges::EdgeArtConverterOptions,
+        emoji::EmojiArtConverterOptions,
+        glyph::{
+            DEFAULT_GLYPH_CELL_HEIGHT, DEFAULT_GLYPH_CELL_WIDTH, GlyphArtConverterOptions, GlyphSet,
+        },
+        half_block::HalfBlockArtConverterOptions,
+        halftone::HalftoneArtConverterOptions,
+        renderer::{RenderMode, Renderer},
+    },
     utils::{
         player::{AsciiPlayer, AsciiPlayerOptions, calculate_frame_time},
-        resize::DEFAULT_FONT_RATIO,
+        resize::{DEFAULT_FONT_RATIO, cell_font_ratio},
+        transform::Transform,
+        trim::DEFAULT_TRIM_TOLERANCE,
     },
 };
 
@@ -24,40 +40,170 @@ fn main() {
     let cli = Cli::parse();
     let mut cmd = Cli::command();
 
+    if cli.list_modes {
+        for render_mode in RenderMode::builtin() {
+            println!("{:<20}{}", render_mode.name(), render_mode.description());
+        }
+
+        return;
+    }
+
+    let mode = cli.render_mode();
+
     #[cfg(target_family = "windows")]
     let images_paths = tapciify::cli::glob_to_paths(&cli.input)
         .unwrap_or_else(|err| cmd.error(ErrorKind::InvalidValue, err).exit());
     #[cfg(not(target_family = "windows"))]
     let images_paths: Vec<PathBuf> = cli.input.into_iter().map(PathBuf::from).collect();
 
-    let font_ratio = match (cli.font_ratio, cli.braille) {
-        (Some(ratio), _) => ratio,
-        (None, true) => DEFAULT_BRAILLE_FONT_RATIO,
-        (None, false) => DEFAULT_FONT_RATIO,
-    };
-
     let (ascii_string, colored) = match (cli.reverse, cli.pixels) {
         (true, false) => (cli.ascii_string.chars().rev().collect(), cli.colored),
         (false, false) => (cli.ascii_string, cli.colored),
         (_, true) => ("█".to_owned(), true),
     };
+    let color_mode = cli.color_mode.unwrap_or(colored.into());
+
+    let render_mode = match mode {
+        Mode::Ascii => RenderMode::Ascii(AsciiArtConverterOptions {
+            ascii_string: ascii_string.to_owned(),
+            color_mode,
+            color_style: cli.color_style,
+            dithering: cli.dithering,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Braille => RenderMode::Braille(BrailleArtConverterOptions {
+            color_mode,
+            dithering: cli.dithering,
+            threshold: cli.binary_threshold,
+            cell_color: cli.cell_color,
+            lightness_model: cli.lightness,
+        }),
+        Mode::BackgroundString => RenderMode::BackgroundString {
+            string: cli
+                .background_string
+                .to_owned()
+                .unwrap_or(ascii_string.to_owned()),
+            options: BackgroundStringArtConverterOptions {
+                color_mode,
+                dithering: cli.dithering,
+                threshold: cli.binary_threshold,
+                lightness_model: cli.lightness,
+            },
+        },
+        Mode::HalfBlock => RenderMode::HalfBlock(HalfBlockArtConverterOptions {
+            color_mode,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Quadrant => RenderMode::Blocks(BlockArtConverterOptions {
+            kind: BlockKind::Quadrant,
+            color_mode,
+            cell_color: cli.cell_color,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Sextant => RenderMode::Blocks(BlockArtConverterOptions {
+            kind: BlockKind::Sextant,
+            color_mode,
+            cell_color: cli.cell_color,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Contour => RenderMode::Contour(ContourArtConverterOptions {
+            levels: cli.contour_levels,
+            line_style: cli.line_style,
+            level_colors: cli.level_colors,
+            color_mode,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Edges => RenderMode::Edges(EdgeArtConverterOptions {
+            ascii_string: ascii_string.to_owned(),
+            color_mode,
+            edge_threshold: cli.edge_threshold,
+            lightness_model: cli.lightness,
+        }),
+        Mode::Emoji => RenderMode::Emoji(EmojiArtConverterOptions {
+            palette: cli.emoji.to_owned(),
+        }),
+        Mode::Glyph => {
+            let glyph_string = cli.glyphs.as_deref().unwrap_or(&ascii_string);
+            let glyphs = match &cli.font {
+                Some(path) => {
+                    let font_data = std::fs::read(path)
+                        .unwrap_or_else(|err| cmd.error(ErrorKind::Io, err).exit());
+
+                    GlyphSet::from_ttf(
+                        &font_data,
+                        glyph_string,
+                        DEFAULT_GLYPH_CELL_WIDTH,
+                        DEFAULT_GLYPH_CELL_HEIGHT,
+                    )
+                }
+                None => GlyphSet::builtin(
+                    glyph_string,
+                    DEFAULT_GLYPH_CELL_WIDTH,
+                    DEFAULT_GLYPH_CELL_HEIGHT,
+                ),
+            }
+            .unwrap_or_else(|err| cmd.error(ErrorKind::InvalidValue, err).exit());
+
+            RenderMode::Glyph(GlyphArtConverterOptions {
+                glyphs,
+                color_mode,
+                cell_color: cli.cell_color,
+                lightness_model: cli.lightness,
+            })
+        }
+        Mode::Halftone => RenderMode::Halftone(HalftoneArtConverterOptions {
+            dots: cli.dots.to_owned(),
+            angle: cli.screen_angle,
+            period: cli.screen_period,
+            font_ratio: cli.font_ratio.unwrap_or(DEFAULT_FONT_RATIO),
+            cmyk: cli.cmyk,
+            color_mode,
+            lightness_model: cli.lightness,
+        }),
+    };
+    // Wide characters take several columns of font
+    let font_ratio = cli
+        .font_ratio
+        .map_or(render_mode.font_ratio(), |font_ratio| {
+            cell_font_ratio(font_ratio, render_mode.cell_columns())
+        });
 
     let frame_time = calculate_frame_time(cli.framerate);
     let options = AsciiPlayerOptions {
         width: cli.width,
         height: cli.height,
         ascii_string,
-        colored,
+        color_mode,
+        color_style: cli.color_style,
         frame_time,
         pre_render: cli.pre_render,
         font_ratio,
         looped: cli.looped,
+        transform: Transform {
+            crop: cli.crop,
+            zoom: cli.zoom,
+            rotation: cli.rotate,
+            flip_horizontal: cli.flip_horizontal,
+            flip_vertical: cli.flip_vertical,
+        },
+        trim: cli
+            .trim
+            .map(|tolerance| tolerance.unwrap_or(DEFAULT_TRIM_TOLERANCE)),
+        trim_scope: cli.trim_scope,
+        preprocessing: cli.adjustments.into(),
+        tone_mapping: cli.tone,
+        tone_scope: cli.tone_scope,
         threshold: cli.threshold,
-        braille: cli.braille,
-        background_string: cli.background_string,
+        alpha_mode: cli.alpha,
+        render_mode: Some(render_mode),
+        output: cli.output,
         ..Default::default()
     };
 
+    if let Err(err) = options.validate() {
+        cmd.error(ErrorKind::InvalidValue, err).exit()
+    }
+
     if let Err(err) = AsciiPlayer::play(&images_paths, &options) {
         cmd.error(ErrorKind::Io, err).exit()
     }
@@ -2,4 +2,6 @@ pub use crate::renderers::ascii::{
     AsciiArt, AsciiArtConverter, AsciiArtConverterError, AsciiArtConverterOptions, AsciiArtPixel,
     ToAsciiArtPixel,
 };
+pub use crate::renderers::renderer::{RenderMode, Renderer};
+pub use crate::utils::color::ColorMode;
 pub use crate::utils::resize::CustomRatioResize;
@@ -43,7 +43,7 @@
 //!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
 //!     .ascii_art(&AsciiArtConverterOptions {
 //!         // Put your other options here
-//!         colored: true,
+//!         color_mode: ColorMode::TrueColor,
 //!         ..Default::default()
 //!     })?;
 //! println!("{}", result);
@@ -52,12 +52,19 @@
 //! ```
 
 use std::cmp::{max, min};
-use std::{error, fmt};
+use std::{error, fmt, str::FromStr};
 
-use colored::Colorize;
 use image::{ImageBuffer, Pixel};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::utils::{
+    color::{ColorMode, ColorStyle},
+    dither::Dithering,
+    lightness::LightnessModel,
+};
 
 /// Default ASCII string, feel free to use your one
 pub const DEFAULT_ASCII_STRING: &str = " .,:;+*?%S#@";
@@ -109,7 +116,7 @@ pub trait AsciiArtConverter {
     ///     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
     ///     .ascii_art(&AsciiArtConverterOptions {
     ///         // Put your other options here
-    ///         colored: true,
+    ///         color_mode: ColorMode::TrueColor,
     ///         ..Default::default()
     ///     })?;
     ///
@@ -117,6 +124,45 @@ pub trait AsciiArtConverter {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// Dithered, so gradients don't band:
+    ///
+    /// ```
+    /// use image::{Luma, GrayImage};
+    /// use tapciify::{prelude::*, utils::dither::Dithering};
+    ///
+    /// # fn main() -> Result<(), AsciiArtConverterError> {
+    /// let img = GrayImage::from_pixel(4, 1, Luma([128]));
+    ///
+    /// let result = img.ascii_art(&AsciiArtConverterOptions {
+    ///     ascii_string: " @".to_owned(),
+    ///     dithering: Dithering::FloydSteinberg,
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(result.to_string(), "@ @ ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Wide and multi-codepoint characters are kept whole, narrow ones are padded:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::prelude::*;
+    ///
+    /// # fn main() -> Result<(), AsciiArtConverterError> {
+    /// let img = GrayImage::from_fn(3, 1, |x, _| Luma([[0, 128, 255][x as usize]]));
+    ///
+    /// let result = img.ascii_art(&AsciiArtConverterOptions {
+    ///     ascii_string: "\u{3000}░あ".to_owned(),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(result.to_string(), "\u{3000}░ あ");
+    /// # Ok(())
+    /// # }
+    /// ```
     fn ascii_art(
         &self,
         options: &AsciiArtConverterOptions,
@@ -146,21 +192,47 @@ where
             return Err(AsciiArtConverterError::SizeError(SizeError));
         }
 
-        #[cfg(feature = "rayon")]
-        let iter = self.par_pixels();
-        #[cfg(not(feature = "rayon"))]
-        let iter = self.pixels();
+        let ramp = Ramp::new(&options.ascii_string)?;
 
-        let characters = iter
-            .map(|pixel| pixel.to_ascii_art_pixel(&options.ascii_string))
-            .collect::<Result<Vec<AsciiArtPixel>, AsciiStringError>>()?;
+        let characters = match options.dithering {
+            Dithering::None => {
+                #[cfg(feature = "rayon")]
+                let iter = self.par_pixels();
+                #[cfg(not(feature = "rayon"))]
+                let iter = self.pixels();
 
-        Ok(AsciiArt::new(
-            characters,
-            self.width(),
-            self.height(),
-            options.colored,
-        ))
+                iter.map(|pixel| {
+                    let lightness = pixel.lightness_with(options.lightness_model);
+                    pixel.to_grapheme_ascii_art_pixel(ramp.grapheme(lightness))
+                })
+                .collect()
+            }
+            dithering => {
+                let lightness = self
+                    .pixels()
+                    .map(|pixel| pixel.lightness_with(options.lightness_model))
+                    .collect::<Vec<f32>>();
+
+                dithering
+                    .quantize(
+                        &lightness,
+                        self.width(),
+                        self.height(),
+                        ramp.graphemes().len(),
+                    )
+                    .into_iter()
+                    .zip(self.pixels())
+                    .map(|(level, pixel)| {
+                        pixel.to_grapheme_ascii_art_pixel(&ramp.graphemes()[level])
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(
+            AsciiArt::new(characters, self.width(), self.height(), options.color_mode)
+                .to_color_style(options.color_style),
+        )
     }
 }
 
@@ -169,15 +241,47 @@ where
 pub struct AsciiArtConverterOptions {
     /// String to represent lightness of pixels
     pub ascii_string: String,
-    /// Make [`AsciiArt`] colored
-    pub colored: bool,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of applying pixel colors in [`AsciiArt`]
+    pub color_style: ColorStyle,
+    /// Dithering used for picking characters from ASCII string
+    pub dithering: Dithering,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+impl AsciiArtConverterOptions {
+    /// Check options before converting images
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::prelude::*;
+    ///
+    /// assert!(AsciiArtConverterOptions::default().validate().is_ok());
+    ///
+    /// let options = AsciiArtConverterOptions {
+    ///     ascii_string: String::new(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(options.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), AsciiArtConverterError> {
+        Ramp::new(&self.ascii_string)?;
+
+        Ok(())
+    }
 }
 
 impl Default for AsciiArtConverterOptions {
     fn default() -> AsciiArtConverterOptions {
         AsciiArtConverterOptions {
             ascii_string: DEFAULT_ASCII_STRING.to_owned(),
-            colored: false,
+            color_mode: ColorMode::None,
+            color_style: ColorStyle::Foreground,
+            dithering: Dithering::None,
+            lightness_model: LightnessModel::default(),
         }
     }
 }
@@ -185,7 +289,7 @@ impl Default for AsciiArtConverterOptions {
 /// Error caused by [`AsciiArtConverter`]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AsciiArtConverterError {
-    /// Error caused by lightness being out of ASCII string in [`ascii_character`]
+    /// Error caused by empty ASCII string, or lightness being out of it in [`ascii_character`]
     AsciiStringError(AsciiStringError),
     /// Error caused by too small image sizes
     SizeError(SizeError),
@@ -237,31 +341,63 @@ pub struct AsciiArt {
     pub width: u32,
     /// Height of the image
     pub height: u32,
-    /// Is ASCII art colored
-    pub colored: bool,
+    /// Color depth of ASCII art
+    pub color_mode: ColorMode,
+    /// Way of applying pixel colors to characters
+    pub color_style: ColorStyle,
 }
 
 impl AsciiArt {
     /// Creates new instance of [`AsciiArt`]
-    pub fn new(characters: Vec<AsciiArtPixel>, width: u32, height: u32, colored: bool) -> AsciiArt {
+    pub fn new(
+        characters: Vec<AsciiArtPixel>,
+        width: u32,
+        height: u32,
+        color_mode: ColorMode,
+    ) -> AsciiArt {
         AsciiArt {
             characters,
             width,
             height,
-            colored,
+            color_mode,
+            color_style: ColorStyle::Foreground,
         }
     }
 
     /// Clones with change in colored
-    pub fn to_colored(mut self, colored: bool) -> AsciiArt {
-        self.colored = colored;
-
-        self
+    #[deprecated(since = "4.0.0", note = "Use `AsciiArt::to_color_mode` instead")]
+    pub fn to_colored(self, colored: bool) -> AsciiArt {
+        self.to_color_mode(colored.into())
     }
 
     /// Mutates colored
+    #[deprecated(since = "4.0.0", note = "Use `AsciiArt::mut_color_mode` instead")]
     pub fn mut_colored(&mut self, colored: bool) {
-        self.colored = colored;
+        self.mut_color_mode(colored.into());
+    }
+
+    /// Clones with change in color mode
+    pub fn to_color_mode(mut self, color_mode: ColorMode) -> AsciiArt {
+        self.color_mode = color_mode;
+
+        self
+    }
+
+    /// Mutates color mode
+    pub fn mut_color_mode(&mut self, color_mode: ColorMode) {
+        self.color_mode = color_mode;
+    }
+
+    /// Clones with change in color style
+    pub fn to_color_style(mut self, color_style: ColorStyle) -> AsciiArt {
+        self.color_style = color_style;
+
+        self
+    }
+
+    /// Mutates color style
+    pub fn mut_color_style(&mut self, color_style: ColorStyle) {
+        self.color_style = color_style;
     }
 }
 
@@ -273,7 +409,9 @@ impl fmt::Display for AsciiArt {
         let iter = self.characters.iter();
 
         let characters = iter
-            .map(|ascii_character| ascii_character.to_string(self.colored))
+            .map(|ascii_character| {
+                ascii_character.to_styled_string(self.color_mode, self.color_style)
+            })
             .collect::<Vec<String>>();
 
         #[cfg(feature = "rayon")]
@@ -303,6 +441,11 @@ pub struct AsciiArtPixel {
     pub b: u8,
     /// Alpha
     pub a: u8,
+    /// Background color, used by renderers packing multiple pixels into one character
+    pub background: Option<image::Rgb<u8>>,
+    /// Text drawn instead of [`AsciiArtPixel::character`], for graphemes made of several
+    /// code points and padded characters
+    pub grapheme: Option<String>,
 }
 
 impl AsciiArtPixel {
@@ -345,36 +488,125 @@ impl AsciiArtPixel {
             g,
             b,
             a,
+            background: None,
+            grapheme: None,
         })
     }
 
     /// Allows you to convert to colored [`String`] or normal [`String`]. Just read the [`AsciiArtPixel::character`] value
-    pub fn to_string(&self, colored: bool) -> String {
-        if colored {
-            return self
-                .character
-                .to_string()
-                .truecolor(self.r, self.g, self.b)
-                .to_string();
-        }
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::{prelude::*, utils::color::ColorMode};
+    ///
+    /// colored::control::set_override(true);
+    ///
+    /// let pixel = image::Rgb::<u8>([255, 0, 0]).to_raw_ascii_art_pixel('@');
+    ///
+    /// assert_eq!(pixel.to_string(ColorMode::None), "@");
+    /// assert_eq!(pixel.to_string(ColorMode::Ansi256), "\x1b[38;5;196m@\x1b[0m");
+    /// ```
+    pub fn to_string(&self, color_mode: ColorMode) -> String {
+        self.to_styled_string(color_mode, ColorStyle::Foreground)
+    }
 
-        self.character.to_string()
+    /// Same as [`AsciiArtPixel::to_string`], but pixel color can be applied to background
+    ///
+    /// Pixels having [`AsciiArtPixel::background`] already are always painted as is, fully
+    /// transparent pixels without it aren't painted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::{prelude::*, utils::color::ColorStyle};
+    ///
+    /// colored::control::set_override(true);
+    ///
+    /// let pixel = image::Rgb::<u8>([255, 0, 0]).to_raw_ascii_art_pixel(' ');
+    ///
+    /// assert_eq!(
+    ///     pixel.to_styled_string(ColorMode::Ansi256, ColorStyle::Background),
+    ///     "\x1b[48;5;196m \x1b[0m"
+    /// );
+    /// ```
+    pub fn to_styled_string(&self, color_mode: ColorMode, color_style: ColorStyle) -> String {
+        let color = [self.r, self.g, self.b];
+
+        let (foreground, background) = match (self.background, self.a) {
+            (Some(background), _) => (Some(color), Some(background.0)),
+            (None, 0) => (None, None),
+            (None, _) => color_style.colors(color),
+        };
+
+        let character = self.character.to_string();
+        let text = self.grapheme.as_deref().unwrap_or(&character);
+
+        color_mode.paint(text, foreground, background)
     }
 }
 
 /// Trait for converting pixels into [`AsciiArtPixel`]
 pub trait ToAsciiArtPixel {
     /// Convert [`image`] crate color types to [`AsciiArtPixel`]
-    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError>;
+    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError> {
+        let graphemes = ascii_graphemes(ascii_string);
+        let grapheme = ascii_grapheme(self.lightness(), &graphemes)?;
+
+        Ok(self.to_grapheme_ascii_art_pixel(grapheme))
+    }
+    /// Lightness of pixel (from 0.0 to 1.0), transparent pixels are dark
+    fn lightness(&self) -> f32;
+    /// Same as [`ToAsciiArtPixel::lightness`], but calculated with [`LightnessModel`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::{prelude::*, utils::lightness::LightnessModel};
+    ///
+    /// let pixel = image::Rgba::<u8>([0, 0, 255, 255]);
+    /// assert_eq!(pixel.lightness_with(LightnessModel::Rec709), pixel.lightness());
+    /// assert!(pixel.lightness_with(LightnessModel::Hsp) > pixel.lightness());
+    ///
+    /// let pixel = image::Rgba::<u8>([0, 0, 255, 0]);
+    /// assert_eq!(pixel.lightness_with(LightnessModel::Hsp), 0.0);
+    /// ```
+    fn lightness_with(&self, model: LightnessModel) -> f32 {
+        let pixel = self.to_raw_ascii_art_pixel(' ');
+
+        model.lightness([pixel.r, pixel.g, pixel.b]) * pixel.a as f32 / 255.0
+    }
     /// Convert [`image`] crate color types to [`AsciiArtPixel`] but just set the char
     fn to_raw_ascii_art_pixel(&self, character: char) -> AsciiArtPixel;
+    /// Same as [`ToAsciiArtPixel::to_raw_ascii_art_pixel`], but with text of any length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::prelude::*;
+    ///
+    /// let pixel = image::Luma([255u8]).to_grapheme_ascii_art_pixel("@");
+    /// assert_eq!((pixel.character, pixel.grapheme), ('@', None));
+    ///
+    /// let pixel = image::Luma([255u8]).to_grapheme_ascii_art_pixel("e\u{301}");
+    /// assert_eq!(pixel.to_string(ColorMode::None), "e\u{301}");
+    /// ```
+    fn to_grapheme_ascii_art_pixel(&self, grapheme: &str) -> AsciiArtPixel {
+        let mut chars = grapheme.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(character), None) => self.to_raw_ascii_art_pixel(character),
+            (character, _) => AsciiArtPixel {
+                grapheme: Some(grapheme.to_owned()),
+                ..self.to_raw_ascii_art_pixel(character.unwrap_or(' '))
+            },
+        }
+    }
 }
 
 impl ToAsciiArtPixel for image::Rgb<u8> {
-    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError> {
-        let luma_pixel = self.to_luma();
-        let character = ascii_character(luma_pixel[0] as f32 / 255.0, ascii_string)?;
-        Ok(self.to_raw_ascii_art_pixel(character))
+    fn lightness(&self) -> f32 {
+        self.to_luma()[0] as f32 / 255.0
     }
 
     fn to_raw_ascii_art_pixel(&self, character: char) -> AsciiArtPixel {
@@ -384,18 +616,16 @@ impl ToAsciiArtPixel for image::Rgb<u8> {
             g: self[1],
             b: self[2],
             a: 255,
+            background: None,
+            grapheme: None,
         }
     }
 }
 
 impl ToAsciiArtPixel for image::Rgba<u8> {
-    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError> {
+    fn lightness(&self) -> f32 {
         let luma_pixel = self.to_luma_alpha();
-        let character = ascii_character(
-            luma_pixel[0] as f32 * luma_pixel[1] as f32 / (255.0 * 255.0),
-            ascii_string,
-        )?;
-        Ok(self.to_raw_ascii_art_pixel(character))
+        luma_pixel[0] as f32 * luma_pixel[1] as f32 / (255.0 * 255.0)
     }
 
     fn to_raw_ascii_art_pixel(&self, character: char) -> AsciiArtPixel {
@@ -405,14 +635,15 @@ impl ToAsciiArtPixel for image::Rgba<u8> {
             g: self[1],
             b: self[2],
             a: self[3],
+            background: None,
+            grapheme: None,
         }
     }
 }
 
 impl ToAsciiArtPixel for image::Luma<u8> {
-    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError> {
-        let character = ascii_character(self[0] as f32 / 255.0, ascii_string)?;
-        Ok(self.to_raw_ascii_art_pixel(character))
+    fn lightness(&self) -> f32 {
+        self[0] as f32 / 255.0
     }
 
     fn to_raw_ascii_art_pixel(&self, character: char) -> AsciiArtPixel {
@@ -422,17 +653,15 @@ impl ToAsciiArtPixel for image::Luma<u8> {
             g: self[0],
             b: self[0],
             a: 255,
+            background: None,
+            grapheme: None,
         }
     }
 }
 
 impl ToAsciiArtPixel for image::LumaA<u8> {
-    fn to_ascii_art_pixel(&self, ascii_string: &str) -> Result<AsciiArtPixel, AsciiStringError> {
-        let character = ascii_character(
-            self[0] as f32 * self[1] as f32 / (255.0 * 255.0),
-            ascii_string,
-        )?;
-        Ok(self.to_raw_ascii_art_pixel(character))
+    fn lightness(&self) -> f32 {
+        self[0] as f32 * self[1] as f32 / (255.0 * 255.0)
     }
 
     fn to_raw_ascii_art_pixel(&self, character: char) -> AsciiArtPixel {
@@ -442,6 +671,8 @@ impl ToAsciiArtPixel for image::LumaA<u8> {
             g: self[0],
             b: self[0],
             a: self[1],
+            background: None,
+            grapheme: None,
         }
     }
 }
@@ -462,17 +693,163 @@ impl ToAsciiArtPixel for image::LumaA<u8> {
 ///
 /// let result = ascii_character(0.0, DEFAULT_ASCII_STRING)?;
 /// assert_eq!(result, ' ');
+///
+/// assert!(ascii_character(0.0, "").is_err());
 /// # Ok(())
 /// # }
 /// ```
 pub fn ascii_character(lightness: f32, ascii_string: &str) -> Result<char, AsciiStringError> {
+    let last = ascii_string
+        .chars()
+        .count()
+        .checked_sub(1)
+        .ok_or(AsciiStringError)?;
+
     ascii_string
         .chars()
-        .nth(((ascii_string.chars().count() - 1) as f32 * lightness) as usize)
+        .nth((last as f32 * lightness) as usize)
         .ok_or(AsciiStringError)
 }
 
-/// Error caused by lightness being out of ASCII string in [`ascii_character`]
+/// Split ASCII string into graphemes, padded with spaces to the display width of the widest one
+///
+/// Keeps characters made of several code points whole, and every pixel of [`AsciiArt`]
+/// equally wide
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::ascii::ascii_graphemes;
+///
+/// assert_eq!(ascii_graphemes(" .@"), [" ", ".", "@"]);
+/// assert_eq!(ascii_graphemes("\u{3000}░▒▓█"), ["\u{3000}", "░ ", "▒ ", "▓ ", "█ "]);
+/// assert_eq!(ascii_graphemes("ae\u{301}"), ["a", "e\u{301}"]);
+/// ```
+pub fn ascii_graphemes(ascii_string: &str) -> Vec<String> {
+    let width = ascii_string_width(ascii_string) as usize;
+
+    ascii_string
+        .graphemes(true)
+        .map(|grapheme| {
+            let padding = width.saturating_sub(grapheme.width());
+            format!("{}{}", grapheme, " ".repeat(padding))
+        })
+        .collect()
+}
+
+/// Display width of the widest grapheme of ASCII string in terminal columns, at least 1
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::ascii::{ascii_string_width, DEFAULT_ASCII_STRING};
+///
+/// assert_eq!(ascii_string_width(DEFAULT_ASCII_STRING), 1);
+/// assert_eq!(ascii_string_width("　あいう"), 2);
+/// ```
+pub fn ascii_string_width(ascii_string: &str) -> u32 {
+    ascii_string
+        .graphemes(true)
+        .map(|grapheme| grapheme.width() as u32)
+        .max()
+        .unwrap_or(1)
+        .max(1)
+}
+
+/// Pick grapheme of [`ascii_graphemes`] by lightness of pixel
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::ascii::{ascii_grapheme, ascii_graphemes, AsciiStringError};
+///
+/// # fn main() -> Result<(), AsciiStringError> {
+/// let graphemes = ascii_graphemes(" .@");
+///
+/// assert_eq!(ascii_grapheme(1.0, &graphemes)?, "@");
+/// assert_eq!(ascii_grapheme(0.0, &graphemes)?, " ");
+/// assert!(ascii_grapheme(1.0, &[] as &[String]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn ascii_grapheme<S: AsRef<str>>(
+    lightness: f32,
+    graphemes: &[S],
+) -> Result<&str, AsciiStringError> {
+    graphemes
+        .get((graphemes.len().saturating_sub(1) as f32 * lightness) as usize)
+        .map(AsRef::as_ref)
+        .ok_or(AsciiStringError)
+}
+
+/// ASCII string checked to have graphemes, split by [`ascii_graphemes`]
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::ascii::{AsciiStringError, Ramp};
+///
+/// # fn main() -> Result<(), AsciiStringError> {
+/// let ramp: Ramp = "\u{3000}░▒▓█".parse()?;
+///
+/// assert_eq!(ramp.width(), 2);
+/// assert_eq!(ramp.grapheme(0.0), "\u{3000}");
+/// assert_eq!(ramp.grapheme(1.0), "█ ");
+/// // Lightness is clamped
+/// assert_eq!(ramp.grapheme(2.0), "█ ");
+///
+/// assert_eq!(Ramp::new(""), Err(AsciiStringError));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ramp {
+    graphemes: Vec<String>,
+    width: u32,
+}
+
+impl Ramp {
+    /// Split ASCII string into graphemes, fails on empty string
+    pub fn new(ascii_string: &str) -> Result<Ramp, AsciiStringError> {
+        let graphemes = ascii_graphemes(ascii_string);
+
+        if graphemes.is_empty() {
+            return Err(AsciiStringError);
+        }
+
+        Ok(Ramp {
+            graphemes,
+            width: ascii_string_width(ascii_string),
+        })
+    }
+
+    /// Graphemes, padded to the same display width
+    pub fn graphemes(&self) -> &[String] {
+        &self.graphemes
+    }
+
+    /// Display width of every grapheme in terminal columns
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Pick grapheme by lightness (from 0.0 to 1.0)
+    pub fn grapheme(&self, lightness: f32) -> &str {
+        let index = ((self.graphemes.len() - 1) as f32 * lightness.clamp(0.0, 1.0)) as usize;
+
+        &self.graphemes[index]
+    }
+}
+
+impl FromStr for Ramp {
+    type Err = AsciiStringError;
+
+    fn from_str(s: &str) -> Result<Ramp, AsciiStringError> {
+        Ramp::new(s)
+    }
+}
+
+/// Error caused by empty ASCII string, or lightness being out of it in [`ascii_character`]
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct AsciiStringError;
 
@@ -480,7 +857,7 @@ impl error::Error for AsciiStringError {}
 
 impl fmt::Display for AsciiStringError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "lightness is out of ASCII string")
+        write!(f, "ASCII string is empty, or lightness is out of it")
     }
 }
 
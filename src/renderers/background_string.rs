@@ -7,8 +7,12 @@ use rayon::prelude::*;
 use crate::prelude::*;
 
 use crate::{
-    renderers::ascii::SizeError,
-    utils::threshold::{DEFAULT_THRESHOLD, ThresholdPixel},
+    renderers::ascii::{Ramp, SizeError},
+    utils::{
+        dither::Dithering,
+        lightness::LightnessModel,
+        threshold::{ThresholdMode, ThresholdPixel},
+    },
 };
 
 /// Convert image into ASCII art with text on the background
@@ -22,7 +26,9 @@ pub trait BackgroundStringArtConverter {
     ///
     /// use tapciify::prelude::*;
     /// use tapciify::utils::resize::DEFAULT_FONT_RATIO;
-    /// use tapciify::renderers::background_string::BackgroundStringArtConverter;
+    /// use tapciify::renderers::background_string::{
+    ///     BackgroundStringArtConverter, BackgroundStringArtConverterOptions,
+    /// };
     ///
     /// # use image::imageops::FilterType;
     ///
@@ -31,20 +37,49 @@ pub trait BackgroundStringArtConverter {
     ///
     /// let result = img
     ///     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
-    ///     .background_string_art("hello world! ", false)?;
+    ///     .background_string_art(
+    ///         "hello world! ",
+    ///         &BackgroundStringArtConverterOptions::default(),
+    ///     )?;
     ///
     /// println!("{}", result);
     /// # Ok(())
     /// # }
     /// ```
-    fn background_string_art(&self, string: &str, colored: bool) -> Result<AsciiArt, SizeError>;
+    ///
+    /// Text is split into graphemes, so wide characters stay aligned:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::renderers::background_string::{
+    ///     BackgroundStringArtConverter, BackgroundStringArtConverterOptions,
+    /// };
+    ///
+    /// # fn main() -> Result<(), tapciify::prelude::AsciiArtConverterError> {
+    /// let img = GrayImage::from_fn(3, 1, |x, _| Luma([if x == 1 { 0 } else { 255 }]));
+    /// let result =
+    ///     img.background_string_art("かな", &BackgroundStringArtConverterOptions::default())?;
+    ///
+    /// assert_eq!(result.to_string(), "か  か");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn background_string_art(
+        &self,
+        string: &str,
+        options: &BackgroundStringArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError>;
 }
 
 impl BackgroundStringArtConverter for image::DynamicImage {
-    fn background_string_art(&self, string: &str, colored: bool) -> Result<AsciiArt, SizeError> {
+    fn background_string_art(
+        &self,
+        string: &str,
+        options: &BackgroundStringArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
         self.clone()
             .into_rgba8()
-            .background_string_art(string, colored)
+            .background_string_art(string, options)
     }
 }
 
@@ -54,35 +89,71 @@ where
     P::Subpixel: Sync,
     Container: std::ops::Deref<Target = [P::Subpixel]>,
 {
-    fn background_string_art(&self, string: &str, colored: bool) -> Result<AsciiArt, SizeError> {
+    fn background_string_art(
+        &self,
+        string: &str,
+        options: &BackgroundStringArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
         if self.width() == 0 || self.height() == 0 {
-            return Err(SizeError);
+            return Err(AsciiArtConverterError::SizeError(SizeError));
         }
 
+        let ramp = Ramp::new(string)?;
+
+        let lightness = self
+            .pixels()
+            .map(|pixel| pixel.lightness_with(options.lightness_model))
+            .collect::<Vec<f32>>();
+        // Every character is single pixel, so local mean is taken from 3x3 blocks
+        let thresholds =
+            options
+                .threshold
+                .thresholds(&lightness, self.width(), self.height(), 3, 3);
+        let lit_pixels =
+            options
+                .dithering
+                .threshold(&lightness, &thresholds, self.width(), self.height());
+
         #[cfg(feature = "rayon")]
         let iter = self.par_pixels();
         #[cfg(not(feature = "rayon"))]
         let iter = self.pixels();
 
+        // Unlit pixels are as wide as graphemes of string
+        let blank = " ".repeat(ramp.width() as usize);
+
         let characters = iter
             .enumerate()
             .map(|(index, pixel)| {
                 (
-                    match pixel.threshold_pixel(DEFAULT_THRESHOLD) {
-                        true => string.chars().nth(index % string.chars().count()).unwrap(),
-                        false => ' ',
+                    match lit_pixels[index] {
+                        true => &ramp.graphemes()[index % ramp.graphemes().len()],
+                        false => &blank,
                     },
                     pixel,
                 )
             })
-            .map(|(character, pixel)| pixel.to_raw_ascii_art_pixel(character))
+            .map(|(grapheme, pixel)| pixel.to_grapheme_ascii_art_pixel(grapheme))
             .collect::<Vec<AsciiArtPixel>>();
 
         Ok(AsciiArt::new(
             characters,
             self.width(),
             self.height(),
-            colored,
+            options.color_mode,
         ))
     }
 }
+
+/// Options for [`BackgroundStringArtConverter::background_string_art`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BackgroundStringArtConverterOptions {
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Dithering used for deciding which pixels are lit
+    pub dithering: Dithering,
+    /// Threshold for lightness of lit pixels
+    pub threshold: ThresholdMode,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
@@ -0,0 +1,376 @@
+//! Converting images to ASCII art using quadrant and sextant block characters
+//!
+//! Every character cell holds a 2x2 (quadrant) or 2x3 (sextant) group of pixels.
+//! Colored output picks the glyph and the foreground/background pair fitting the group best
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::SizeError,
+    utils::{
+        color::CellColor,
+        lightness::LightnessModel,
+        threshold::{DEFAULT_THRESHOLD, ThresholdPixel},
+    },
+};
+
+/// Quadrant characters aspect ratio
+pub const DEFAULT_QUADRANT_FONT_RATIO: f64 = 11.0 / 24.0;
+
+/// Sextant characters aspect ratio
+pub const DEFAULT_SEXTANT_FONT_RATIO: f64 = 11.0 / 16.0;
+
+/// Quadrant characters, indexed by mask
+///
+/// Bits are placed from left to right, from top to bottom
+const QUADRANT_CHARACTERS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// Kind of block characters
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum BlockKind {
+    /// 2x2 pixels per character, U+2596..U+259F
+    #[default]
+    Quadrant,
+    /// 2x3 pixels per character, U+1FB00..U+1FB3B
+    Sextant,
+}
+
+impl BlockKind {
+    /// Size of the pixel group represented by one character
+    pub fn cell_size(&self) -> (u32, u32) {
+        match self {
+            BlockKind::Quadrant => (2, 2),
+            BlockKind::Sextant => (2, 3),
+        }
+    }
+
+    /// Aspect ratio to use for resizing
+    pub fn font_ratio(&self) -> f64 {
+        match self {
+            BlockKind::Quadrant => DEFAULT_QUADRANT_FONT_RATIO,
+            BlockKind::Sextant => DEFAULT_SEXTANT_FONT_RATIO,
+        }
+    }
+
+    /// Convert mask of lit pixels into block character
+    pub fn character(&self, mask: u8) -> char {
+        match self {
+            BlockKind::Quadrant => quadrant_character(mask),
+            BlockKind::Sextant => sextant_character(mask),
+        }
+    }
+}
+
+/// Convert mask of lit pixels into quadrant character
+///
+/// Grid of bits placement
+///
+/// |---|---|
+/// | 0 | 1 |
+/// | 2 | 3 |
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::blocks::quadrant_character;
+///
+/// assert_eq!(quadrant_character(0b0000), ' ');
+/// assert_eq!(quadrant_character(0b0011), '▀');
+/// assert_eq!(quadrant_character(0b1001), '▚');
+/// assert_eq!(quadrant_character(0b1111), '█');
+/// ```
+pub fn quadrant_character(mask: u8) -> char {
+    QUADRANT_CHARACTERS[(mask & 0b1111) as usize]
+}
+
+/// Convert mask of lit pixels into sextant character
+///
+/// Grid of bits placement
+///
+/// |---|---|
+/// | 0 | 1 |
+/// | 2 | 3 |
+/// | 4 | 5 |
+///
+/// Sextant block has no characters for empty, full, left and right half masks,
+/// so these are mapped to ` `, `█`, `▌` and `▐`
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::blocks::sextant_character;
+///
+/// assert_eq!(sextant_character(0b000001), '🬀');
+/// assert_eq!(sextant_character(0b010101), '▌');
+/// assert_eq!(sextant_character(0b011111), '🬝');
+/// assert_eq!(sextant_character(0b111110), '🬻');
+/// assert_eq!(sextant_character(0b111111), '█');
+/// ```
+pub fn sextant_character(mask: u8) -> char {
+    let mask = mask & 0b111111;
+
+    match mask {
+        0b000000 => ' ',
+        0b010101 => '▌',
+        0b101010 => '▐',
+        0b111111 => '█',
+        _ => {
+            // Codepoints skip the masks of left and right halves
+            let skipped = (mask > 0b010101) as u32 + (mask > 0b101010) as u32;
+            std::char::from_u32(0x1FB00 + mask as u32 - 1 - skipped).unwrap_or(' ')
+        }
+    }
+}
+
+/// Allows to render your images using quadrant and sextant block characters
+pub trait BlockArtConverter {
+    /// Convert image into ASCII art using block characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::blocks::{BlockArtConverter, BlockArtConverterOptions, BlockKind},
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    /// let options = BlockArtConverterOptions {
+    ///     kind: BlockKind::Sextant,
+    ///     color_mode: ColorMode::TrueColor,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(
+    ///         Some(64 * 2),
+    ///         None,
+    ///         options.kind.font_ratio(),
+    ///         FilterType::Triangle,
+    ///     )
+    ///     .block_art(&options)?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Two colors are fitted into every character:
+    ///
+    /// ```
+    /// use image::{Rgb, RgbImage};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::blocks::{BlockArtConverter, BlockArtConverterOptions},
+    /// };
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let img = RgbImage::from_fn(2, 2, |x, _| if x == 0 { Rgb([255, 0, 0]) } else { Rgb([0, 0, 255]) });
+    /// let result = img.block_art(&BlockArtConverterOptions {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// let pixel = &result.characters[0];
+    /// assert_eq!(pixel.character, '▐');
+    /// assert_eq!((pixel.r, pixel.g, pixel.b), (0, 0, 255));
+    /// assert_eq!(pixel.background, Some(Rgb([255, 0, 0])));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn block_art(&self, options: &BlockArtConverterOptions) -> Result<AsciiArt, SizeError>;
+}
+
+impl BlockArtConverter for image::DynamicImage {
+    fn block_art(&self, options: &BlockArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        self.to_rgba8().block_art(options)
+    }
+}
+
+impl<P, Container> BlockArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + ThresholdPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn block_art(&self, options: &BlockArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(SizeError);
+        }
+
+        let (cell_width, cell_height) = options.kind.cell_size();
+        let block_width = width.div_ceil(cell_width);
+        let block_height = height.div_ceil(cell_height);
+        let total_chars = block_width * block_height;
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..total_chars).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..total_chars;
+
+        let characters = iter
+            .map(|i| {
+                let x = (i % block_width) * cell_width;
+                let y = (i / block_width) * cell_height;
+                (x, y)
+            })
+            .map(|(x, y)| {
+                // Pixels out of image on the right and bottom edges are skipped
+                let cell = (0..cell_width * cell_height)
+                    .map(|i| (x + i % cell_width, y + i / cell_width))
+                    .map(|(x, y)| (x < width && y < height).then(|| self.get_pixel(x, y)))
+                    .collect::<Vec<Option<&P>>>();
+
+                if options.color_mode.is_colored() {
+                    fit_block(options, &cell)
+                } else {
+                    threshold_block(options, &cell)
+                }
+            })
+            .collect();
+
+        Ok(AsciiArt::new(
+            characters,
+            block_width,
+            block_height,
+            options.color_mode,
+        ))
+    }
+}
+
+/// Options for [`BlockArtConverter::block_art`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BlockArtConverterOptions {
+    /// Quadrant or sextant blocks
+    pub kind: BlockKind,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of picking colors of character from its pixels
+    pub cell_color: CellColor,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+/// RGBA color of pixel
+fn pixel_color<P: ToAsciiArtPixel>(pixel: &P) -> [u8; 4] {
+    let pixel = pixel.to_raw_ascii_art_pixel(' ');
+
+    [pixel.r, pixel.g, pixel.b, pixel.a]
+}
+
+/// Pick glyph for pixel group using threshold, colored by [`CellColor`]
+fn threshold_block<P: ToAsciiArtPixel + ThresholdPixel>(
+    options: &BlockArtConverterOptions,
+    cell: &[Option<&P>],
+) -> AsciiArtPixel {
+    let pixels = cell
+        .iter()
+        .map(|pixel| {
+            pixel.map(|pixel| {
+                (
+                    pixel_color(pixel),
+                    pixel.threshold_pixel_with(DEFAULT_THRESHOLD, options.lightness_model),
+                )
+            })
+        })
+        .collect::<Vec<Option<([u8; 4], bool)>>>();
+
+    let mask = pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, pixel)| pixel.is_some_and(|(_, lit)| lit))
+        .fold(0u8, |mask, (i, _)| mask | 1 << i);
+
+    let [r, g, b, a] = options
+        .cell_color
+        .pick(&pixels.into_iter().flatten().collect::<Vec<_>>());
+
+    AsciiArtPixel {
+        character: options.kind.character(mask),
+        r,
+        g,
+        b,
+        a,
+        ..Default::default()
+    }
+}
+
+/// Pick glyph and foreground/background pair with the lowest squared error
+///
+/// Colors of both pixel groups are picked by [`CellColor`], foreground pixels count as lit
+fn fit_block<P: ToAsciiArtPixel>(
+    options: &BlockArtConverterOptions,
+    cell: &[Option<&P>],
+) -> AsciiArtPixel {
+    let pixels = cell
+        .iter()
+        .map(|pixel| pixel.map(pixel_color))
+        .collect::<Vec<Option<[u8; 4]>>>();
+
+    let present_mask = pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, pixel)| pixel.is_some())
+        .fold(0u8, |mask, (i, _)| mask | 1 << i);
+
+    // Going from the full mask, so uniform groups become `█`
+    let (mask, foreground, background, _) = (1..=present_mask)
+        .rev()
+        .filter(|mask| mask & !present_mask == 0)
+        .map(|mask| {
+            let (foreground, background): (Vec<_>, Vec<_>) = pixels
+                .iter()
+                .enumerate()
+                .filter_map(|(i, pixel)| pixel.map(|pixel| (pixel, mask & 1 << i != 0)))
+                .partition(|(_, lit)| *lit);
+
+            let foreground_color = options.cell_color.pick(&foreground);
+            let background_color =
+                (!background.is_empty()).then(|| options.cell_color.pick(&background));
+
+            let error = squared_error(&foreground, foreground_color)
+                + background_color.map_or(0, |color| squared_error(&background, color));
+
+            (mask, foreground_color, background_color, error)
+        })
+        .min_by_key(|(_, _, _, error)| *error)
+        .unwrap_or_default();
+
+    let [r, g, b, a] = foreground;
+
+    AsciiArtPixel {
+        character: options.kind.character(mask),
+        r,
+        g,
+        b,
+        a,
+        background: background.map(|[r, g, b, _]| image::Rgb([r, g, b])),
+        grapheme: None,
+    }
+}
+
+/// Sum of squared distances between pixels and color
+fn squared_error(pixels: &[([u8; 4], bool)], color: [u8; 4]) -> u32 {
+    pixels
+        .iter()
+        .map(|(pixel, _)| {
+            (0..3)
+                .map(|i| (pixel[i] as i32 - color[i] as i32).pow(2) as u32)
+                .sum::<u32>()
+        })
+        .sum()
+}
@@ -7,7 +7,12 @@ use rayon::prelude::*;
 use crate::{
     prelude::*,
     renderers::ascii::SizeError,
-    utils::threshold::{DEFAULT_THRESHOLD, ThresholdPixel},
+    utils::{
+        color::CellColor,
+        dither::Dithering,
+        lightness::LightnessModel,
+        threshold::{ThresholdMode, ThresholdPixel},
+    },
 };
 
 /// Braille characters aspect ratio
@@ -49,6 +54,36 @@ pub fn boolean_array_to_braille(array: &[bool; 8]) -> char {
     std::char::from_u32(codepoint).unwrap_or(' ')
 }
 
+/// Convert braille character into array of booleans, in the same order as [`boolean_array_to_braille`]
+///
+/// Returns [`None`] for non braille characters
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::braille::{boolean_array_to_braille, braille_to_boolean_array};
+///
+/// assert_eq!(
+///     braille_to_boolean_array('⢑'),
+///     Some([true, false, false, false, true, false, false, true])
+/// );
+/// assert_eq!(braille_to_boolean_array('a'), None);
+///
+/// // Every dot pattern survives round trip
+/// for pattern in 0..=255u8 {
+///     let array = std::array::from_fn(|i| pattern & (1 << i) != 0);
+///
+///     assert_eq!(braille_to_boolean_array(boolean_array_to_braille(&array)), Some(array));
+/// }
+/// ```
+pub fn braille_to_boolean_array(character: char) -> Option<[bool; 8]> {
+    let dots = (character as u32)
+        .checked_sub(0x2800)
+        .filter(|dots| *dots <= 0xFF)?;
+
+    Some(std::array::from_fn(|i| dots & (1 << i) != 0))
+}
+
 /// Allows to render your images using Braille characters
 pub trait BrailleArtConverter {
     /// Convert image into ASCII art using Braille characters
@@ -60,7 +95,9 @@ pub trait BrailleArtConverter {
     ///
     /// use tapciify::{
     ///     prelude::*,
-    ///     renderers::braille::{BrailleArtConverter, DEFAULT_BRAILLE_FONT_RATIO},
+    ///     renderers::braille::{
+    ///         BrailleArtConverter, BrailleArtConverterOptions, DEFAULT_BRAILLE_FONT_RATIO,
+    ///     },
     /// };
     ///
     /// # use image::imageops::FilterType;
@@ -76,18 +113,39 @@ pub trait BrailleArtConverter {
     ///         FilterType::Triangle,
     ///     )
     ///     .to_luma8()
-    ///     .braille_art(false)?;
+    ///     .braille_art(&BrailleArtConverterOptions::default())?;
     ///
     /// println!("{}", result);
     /// # Ok(())
     /// # }
     /// ```
-    fn braille_art(&self, colored: bool) -> Result<AsciiArt, SizeError>;
+    ///
+    /// Partial cells are padded with unlit dots:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::renderers::braille::{BrailleArtConverter, BrailleArtConverterOptions};
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let img = GrayImage::from_pixel(3, 5, Luma([255]));
+    /// let result = img.braille_art(&BrailleArtConverterOptions::default())?;
+    ///
+    /// assert_eq!(result.to_string(), "⣿⡇\n⠉⠁");
+    ///
+    /// // Only the right column is lit
+    /// let img = GrayImage::from_fn(2, 4, |x, _| Luma([x as u8 * 255]));
+    /// let result = img.braille_art(&BrailleArtConverterOptions::default())?;
+    ///
+    /// assert_eq!(result.to_string(), "⢸");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn braille_art(&self, options: &BrailleArtConverterOptions) -> Result<AsciiArt, SizeError>;
 }
 
 impl BrailleArtConverter for image::DynamicImage {
-    fn braille_art(&self, colored: bool) -> Result<AsciiArt, SizeError> {
-        self.to_rgba8().braille_art(colored)
+    fn braille_art(&self, options: &BrailleArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        self.to_rgba8().braille_art(options)
     }
 }
 
@@ -97,16 +155,28 @@ where
     P::Subpixel: Sync,
     Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
 {
-    fn braille_art(&self, colored: bool) -> Result<AsciiArt, SizeError> {
+    fn braille_art(&self, options: &BrailleArtConverterOptions) -> Result<AsciiArt, SizeError> {
         let width = self.width();
         let height = self.height();
 
-        if width < 4 || height < 8 {
+        if width == 0 || height == 0 {
             return Err(SizeError);
         }
 
-        let braille_width = width / 2;
-        let braille_height = height / 4;
+        let lightness = self
+            .pixels()
+            .map(|pixel| pixel.lightness_with(options.lightness_model))
+            .collect::<Vec<f32>>();
+        let thresholds = options
+            .threshold
+            .thresholds(&lightness, width, height, 2, 4);
+        let lit_pixels = options
+            .dithering
+            .threshold(&lightness, &thresholds, width, height);
+
+        // Partial cells on the right and bottom edges are padded with unlit dots
+        let braille_width = width.div_ceil(2);
+        let braille_height = height.div_ceil(4);
         let total_chars = braille_width * braille_height;
 
         #[cfg(feature = "rayon")]
@@ -121,12 +191,30 @@ where
                 (x, y)
             })
             .map(|(x, y)| {
-                let braille_array = calc_braille_pixels(x, y)
-                    .map(|(x, y)| self.get_pixel(x, y).threshold_pixel(DEFAULT_THRESHOLD));
+                // Dots out of image are unlit and don't affect color
+                let dots = calc_braille_pixels(x, y).map(|(x, y)| {
+                    (x < width && y < height).then(|| {
+                        let pixel = self.get_pixel(x, y).to_raw_ascii_art_pixel(' ');
+                        let lit = lit_pixels[(y * width + x) as usize];
 
-                // Top left pixel (used only for colors)
-                self.get_pixel(x, y)
-                    .to_raw_ascii_art_pixel(boolean_array_to_braille(&braille_array))
+                        ([pixel.r, pixel.g, pixel.b, pixel.a], lit)
+                    })
+                });
+
+                let braille_array = dots.map(|dot| dot.is_some_and(|(_, lit)| lit));
+                let [r, g, b, a] = options
+                    .cell_color
+                    .pick(&dots.into_iter().flatten().collect::<Vec<_>>());
+
+                AsciiArtPixel {
+                    character: boolean_array_to_braille(&braille_array),
+                    r,
+                    g,
+                    b,
+                    a,
+                    background: None,
+                    grapheme: None,
+                }
             })
             .collect();
 
@@ -134,20 +222,48 @@ where
             characters,
             braille_width,
             braille_height,
-            colored,
+            options.color_mode,
         ))
     }
 }
 
-/// Calculates braille pixels positions
+/// Options for [`BrailleArtConverter::braille_art`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BrailleArtConverterOptions {
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Dithering used for deciding which dots are lit
+    pub dithering: Dithering,
+    /// Threshold for lightness of lit dots
+    pub threshold: ThresholdMode,
+    /// Way of picking color of braille character from its dots
+    pub cell_color: CellColor,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+/// Calculates braille pixels positions, in order of [`boolean_array_to_braille`] dots
+///
+/// Positions may be out of image on the right and bottom edges
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::braille::calc_braille_pixels;
+///
+/// assert_eq!(
+///     calc_braille_pixels(2, 4),
+///     [(2, 4), (2, 5), (2, 6), (3, 4), (3, 5), (3, 6), (2, 7), (3, 7)]
+/// );
+/// ```
 pub fn calc_braille_pixels(x: u32, y: u32) -> [(u32, u32); 8] {
     [
         (x, y),
         (x, y + 1),
         (x, y + 2),
         (x + 1, y),
-        (x, y + 1),
-        (x, y + 2),
+        (x + 1, y + 1),
+        (x + 1, y + 2),
         (x, y + 3),
         (x + 1, y + 3),
     ]
@@ -0,0 +1,216 @@
+//! Converting images to topographic-style art, drawing isolines of lightness with
+//! box-drawing characters
+//!
+//! Every character is a marching squares cell, with corners in the pixel and its right,
+//! bottom and bottom right neighbours
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{prelude::*, renderers::ascii::SizeError, utils::lightness::LightnessModel};
+
+/// Default count of lightness levels, separated by isolines
+pub const DEFAULT_CONTOUR_LEVELS: u32 = 8;
+
+/// Isoline characters with sharp corners, indexed by marching squares case
+const SHARP_LINES: [char; 16] = [
+    ' ', '┐', '┌', '─', '└', '╳', '│', '┘', '┘', '│', '╳', '└', '─', '┌', '┐', ' ',
+];
+
+/// Isoline characters with rounded corners, indexed by marching squares case
+const ROUNDED_LINES: [char; 16] = [
+    ' ', '╮', '╭', '─', '╰', '╳', '│', '╯', '╯', '│', '╳', '╰', '─', '╭', '╮', ' ',
+];
+
+/// Style of box-drawing characters for isolines
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum LineStyle {
+    /// Sharp corners: ┌ ┐ └ ┘
+    #[default]
+    Sharp,
+    /// Rounded corners: ╭ ╮ ╰ ╯
+    Rounded,
+}
+
+impl LineStyle {
+    /// Character of marching squares case
+    ///
+    /// Bits of case are set for corners above isoline: 8 for top left, 4 for top right,
+    /// 2 for bottom right and 1 for bottom left
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::contour::LineStyle;
+    ///
+    /// // Top half is above isoline
+    /// assert_eq!(LineStyle::Sharp.character(0b1100), '─');
+    /// // Only bottom right corner is above isoline
+    /// assert_eq!(LineStyle::Sharp.character(0b0010), '┌');
+    /// assert_eq!(LineStyle::Rounded.character(0b0010), '╭');
+    /// // Flat cell
+    /// assert_eq!(LineStyle::Rounded.character(0b1111), ' ');
+    /// ```
+    pub fn character(&self, case: u8) -> char {
+        let lines = match self {
+            LineStyle::Sharp => &SHARP_LINES,
+            LineStyle::Rounded => &ROUNDED_LINES,
+        };
+
+        lines[(case & 0b1111) as usize]
+    }
+}
+
+/// Allows to render your images as isolines of lightness
+pub trait ContourArtConverter {
+    /// Convert image into ASCII art, drawing isolines between lightness levels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::contour::{ContourArtConverter, ContourArtConverterOptions},
+    ///     utils::resize::DEFAULT_FONT_RATIO,
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+    ///     .contour_art(&ContourArtConverterOptions::default())?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Boundary between dark and light halves:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::renderers::contour::{ContourArtConverter, ContourArtConverterOptions};
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let img = GrayImage::from_fn(4, 2, |x, _| Luma([if x < 2 { 0 } else { 255 }]));
+    /// let result = img.contour_art(&ContourArtConverterOptions::default())?;
+    ///
+    /// assert_eq!(result.to_string(), " │  \n │  ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn contour_art(&self, options: &ContourArtConverterOptions) -> Result<AsciiArt, SizeError>;
+}
+
+impl ContourArtConverter for image::DynamicImage {
+    fn contour_art(&self, options: &ContourArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        self.to_rgba8().contour_art(options)
+    }
+}
+
+impl<P, Container> ContourArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn contour_art(&self, options: &ContourArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(SizeError);
+        }
+
+        let levels = options.levels.max(1);
+        let bands = self
+            .pixels()
+            .map(|pixel| pixel.lightness_with(options.lightness_model))
+            .map(|lightness| ((lightness * levels as f32) as u32).min(levels - 1))
+            .collect::<Vec<u32>>();
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..width * height).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..width * height;
+
+        let characters = iter
+            .map(|i| (i % width, i / width))
+            .map(|(x, y)| {
+                // Corners out of image repeat the last row and column
+                let right = (x + 1).min(width - 1);
+                let bottom = (y + 1).min(height - 1);
+                let corners = [(x, y), (right, y), (right, bottom), (x, bottom)]
+                    .map(|(x, y)| bands[(y * width + x) as usize]);
+
+                // Isoline between the lowest band of cell and the next one
+                let lowest = corners.into_iter().min().unwrap_or_default();
+                let case = corners
+                    .into_iter()
+                    .fold(0, |case, band| (case << 1) | (band > lowest) as u8);
+
+                let pixel = self.get_pixel(x, y);
+                let mut ascii_pixel =
+                    pixel.to_raw_ascii_art_pixel(options.line_style.character(case));
+
+                if options.level_colors {
+                    [ascii_pixel.r, ascii_pixel.g, ascii_pixel.b] =
+                        level_color((lowest + 1) as f32 / levels as f32);
+                }
+
+                ascii_pixel
+            })
+            .collect();
+
+        Ok(AsciiArt::new(characters, width, height, options.color_mode))
+    }
+}
+
+/// Color of isoline on topographic map, from blue for dark levels to red for light ones
+fn level_color(level: f32) -> [u8; 3] {
+    let hue = (1.0 - level.clamp(0.0, 1.0)) * 4.0;
+    let rising = (hue.fract() * 255.0).round() as u8;
+    let falling = 255 - rising;
+
+    match hue as u32 {
+        0 => [255, rising, 0],
+        1 => [falling, 255, 0],
+        2 => [0, 255, rising],
+        3 => [0, falling, 255],
+        _ => [0, 0, 255],
+    }
+}
+
+/// Options for [`ContourArtConverter::contour_art`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContourArtConverterOptions {
+    /// Count of lightness levels, separated by isolines
+    pub levels: u32,
+    /// Style of box-drawing characters
+    pub line_style: LineStyle,
+    /// Paint isolines by their level instead of color of pixels
+    pub level_colors: bool,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+impl Default for ContourArtConverterOptions {
+    fn default() -> ContourArtConverterOptions {
+        ContourArtConverterOptions {
+            levels: DEFAULT_CONTOUR_LEVELS,
+            line_style: LineStyle::default(),
+            level_colors: false,
+            color_mode: ColorMode::None,
+            lightness_model: LightnessModel::default(),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Converting images to ASCII art drawing edges with line characters
+//!
+//! Sobel operator finds gradients of lightness: pixels with strong gradients get
+//! `|`, `/`, `-`, `_` or `\` along the edge, flat ones fall back to ASCII string
+
+use image::{GrayImage, ImageBuffer, Luma, Pixel};
+use imageproc::gradients::{horizontal_sobel, vertical_sobel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::{DEFAULT_ASCII_STRING, Ramp, SizeError},
+    utils::lightness::LightnessModel,
+};
+
+/// Default strength of gradient (from 0.0 to 1.0) to draw edge
+pub const DEFAULT_EDGE_THRESHOLD: f32 = 0.3;
+
+/// Maximal response of Sobel kernel to a single axis
+const SOBEL_MAX: f32 = 4.0 * 255.0;
+
+/// Convert gradient of lightness into character drawing edge across it
+///
+/// Horizontal edges with the light side on top are drawn with `_`, the other ones with `-`
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::edges::edge_character;
+///
+/// // Dark on the left, light on the right
+/// assert_eq!(edge_character(1.0, 0.0), '|');
+/// // Dark on the top, light on the bottom
+/// assert_eq!(edge_character(0.0, 1.0), '-');
+/// assert_eq!(edge_character(0.0, -1.0), '_');
+/// // Light in the bottom right corner
+/// assert_eq!(edge_character(1.0, 1.0), '/');
+/// assert_eq!(edge_character(-1.0, 1.0), '\\');
+/// ```
+pub fn edge_character(gradient_x: f32, gradient_y: f32) -> char {
+    // Edge goes across gradient, angle is measured with y axis pointing up
+    let angle = (-gradient_x)
+        .atan2(-gradient_y)
+        .to_degrees()
+        .rem_euclid(180.0);
+
+    match angle {
+        22.5..67.5 => '/',
+        67.5..112.5 => '|',
+        112.5..157.5 => '\\',
+        _ if gradient_y < 0.0 => '_',
+        _ => '-',
+    }
+}
+
+/// Allows to render your images with edges drawn using line characters
+pub trait EdgeArtConverter {
+    /// Convert image into ASCII art, drawing edges with characters of their direction
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::edges::{EdgeArtConverter, EdgeArtConverterOptions},
+    ///     utils::resize::DEFAULT_FONT_RATIO,
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+    ///     .edge_art(&EdgeArtConverterOptions::default())?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Flat regions use ASCII string:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::edges::{EdgeArtConverter, EdgeArtConverterOptions},
+    /// };
+    ///
+    /// # fn main() -> Result<(), AsciiArtConverterError> {
+    /// let img = GrayImage::from_fn(6, 1, |x, _| Luma([if x < 3 { 0 } else { 255 }]));
+    /// let result = img.edge_art(&EdgeArtConverterOptions {
+    ///     ascii_string: " @".to_owned(),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(result.to_string(), "  ||@@");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn edge_art(
+        &self,
+        options: &EdgeArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError>;
+}
+
+impl EdgeArtConverter for image::DynamicImage {
+    fn edge_art(
+        &self,
+        options: &EdgeArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        self.to_rgba8().edge_art(options)
+    }
+}
+
+impl<P, Container> EdgeArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn edge_art(
+        &self,
+        options: &EdgeArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(AsciiArtConverterError::SizeError(SizeError));
+        }
+        let ramp = Ramp::new(&options.ascii_string)?;
+
+        let columns = ramp.width() as usize;
+
+        let lightness = GrayImage::from_fn(width, height, |x, y| {
+            let lightness = self.get_pixel(x, y).lightness_with(options.lightness_model);
+            Luma([(lightness * 255.0).round() as u8])
+        });
+        let gradients_x = horizontal_sobel(&lightness);
+        let gradients_y = vertical_sobel(&lightness);
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..width * height).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..width * height;
+
+        let characters = iter
+            .map(|i| (i % width, i / width))
+            .map(|(x, y)| {
+                let pixel = self.get_pixel(x, y);
+
+                let gradient_x = gradients_x.get_pixel(x, y)[0] as f32 / SOBEL_MAX;
+                let gradient_y = gradients_y.get_pixel(x, y)[0] as f32 / SOBEL_MAX;
+
+                match gradient_x.hypot(gradient_y) > options.edge_threshold {
+                    true => {
+                        // Padded to be as wide as graphemes of ASCII string
+                        let edge = edge_character(gradient_x, gradient_y);
+                        pixel.to_grapheme_ascii_art_pixel(&format!("{:<1$}", edge, columns))
+                    }
+                    false => pixel.to_grapheme_ascii_art_pixel(
+                        ramp.grapheme(pixel.lightness_with(options.lightness_model)),
+                    ),
+                }
+            })
+            .collect();
+
+        Ok(AsciiArt::new(characters, width, height, options.color_mode))
+    }
+}
+
+/// Options for [`EdgeArtConverter::edge_art`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeArtConverterOptions {
+    /// String to represent lightness of pixels without edges
+    pub ascii_string: String,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Strength of gradient (from 0.0 to 1.0) to draw edge
+    pub edge_threshold: f32,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+impl Default for EdgeArtConverterOptions {
+    fn default() -> EdgeArtConverterOptions {
+        EdgeArtConverterOptions {
+            ascii_string: DEFAULT_ASCII_STRING.to_owned(),
+            color_mode: ColorMode::None,
+            edge_threshold: DEFAULT_EDGE_THRESHOLD,
+            lightness_model: LightnessModel::default(),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Converting images to mosaic of emoji, which keep colors without ANSI escape codes
+//!
+//! Every pixel becomes an emoji with the perceptually closest color. Emoji take two
+//! terminal columns, so resize images with [`EMOJI_FONT_RATIO`]
+
+use std::{error, fmt, str::FromStr};
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::SizeError,
+    utils::{
+        color::{parse_hex_color, rgb_to_lab},
+        resize::{DEFAULT_FONT_RATIO, WIDE_CELL_COLUMNS, cell_font_ratio},
+    },
+};
+
+/// Aspect ratio of double-width emoji, with Consolas font family
+pub const EMOJI_FONT_RATIO: f64 = cell_font_ratio(DEFAULT_FONT_RATIO, WIDE_CELL_COLUMNS);
+
+/// Colored squares and their reference colors
+pub const SQUARE_EMOJI: [(char, [u8; 3]); 9] = [
+    ('🟥', [221, 46, 68]),
+    ('🟧', [244, 144, 12]),
+    ('🟨', [253, 203, 88]),
+    ('🟩', [120, 177, 89]),
+    ('🟦', [85, 172, 238]),
+    ('🟪', [170, 142, 214]),
+    ('⬛', [49, 55, 61]),
+    ('⬜', [230, 231, 232]),
+    ('🟫', [193, 105, 79]),
+];
+
+/// Colored circles and their reference colors
+pub const CIRCLE_EMOJI: [(char, [u8; 3]); 9] = [
+    ('🔴', [221, 46, 68]),
+    ('🟠', [244, 144, 12]),
+    ('🟡', [253, 203, 88]),
+    ('🟢', [120, 177, 89]),
+    ('🔵', [85, 172, 238]),
+    ('🟣', [170, 142, 214]),
+    ('⚫', [49, 55, 61]),
+    ('⚪', [230, 231, 232]),
+    ('🟤', [193, 105, 79]),
+];
+
+/// Emoji to pick from, with their reference colors
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmojiPalette {
+    emoji: Vec<(char, [u8; 3])>,
+}
+
+impl EmojiPalette {
+    /// Create palette of emoji and their reference colors
+    pub fn new(emoji: Vec<(char, [u8; 3])>) -> Result<EmojiPalette, EmojiPaletteError> {
+        if emoji.is_empty() {
+            return Err(EmojiPaletteError::Empty);
+        }
+
+        Ok(EmojiPalette { emoji })
+    }
+
+    /// Palette of [`SQUARE_EMOJI`]
+    pub fn squares() -> EmojiPalette {
+        EmojiPalette {
+            emoji: SQUARE_EMOJI.to_vec(),
+        }
+    }
+
+    /// Palette of [`CIRCLE_EMOJI`]
+    pub fn circles() -> EmojiPalette {
+        EmojiPalette {
+            emoji: CIRCLE_EMOJI.to_vec(),
+        }
+    }
+
+    /// Emoji and their reference colors
+    pub fn emoji(&self) -> &[(char, [u8; 3])] {
+        &self.emoji
+    }
+}
+
+impl Default for EmojiPalette {
+    fn default() -> EmojiPalette {
+        EmojiPalette::squares()
+    }
+}
+
+impl FromStr for EmojiPalette {
+    type Err = EmojiPaletteError;
+
+    /// Parse `squares`, `circles` or comma separated list of `<emoji>=<hex color>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::emoji::{EmojiPalette, EmojiPaletteError};
+    ///
+    /// # fn main() -> Result<(), EmojiPaletteError> {
+    /// assert_eq!("squares".parse::<EmojiPalette>()?, EmojiPalette::squares());
+    /// assert_eq!(
+    ///     "🍎=#d0312d, 🍌=f6d743".parse::<EmojiPalette>()?.emoji(),
+    ///     [('🍎', [208, 49, 45]), ('🍌', [246, 215, 67])]
+    /// );
+    /// assert!("🍎=red".parse::<EmojiPalette>().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_str(s: &str) -> Result<EmojiPalette, EmojiPaletteError> {
+        match s {
+            "squares" => Ok(EmojiPalette::squares()),
+            "circles" => Ok(EmojiPalette::circles()),
+            _ => EmojiPalette::new(
+                s.split(',')
+                    .map(str::trim)
+                    .filter(|entry| !entry.is_empty())
+                    .map(parse_emoji_entry)
+                    .collect::<Result<Vec<_>, EmojiPaletteError>>()?,
+            ),
+        }
+    }
+}
+
+/// Parse `<emoji>=<hex color>` entry of palette
+fn parse_emoji_entry(entry: &str) -> Result<(char, [u8; 3]), EmojiPaletteError> {
+    let invalid = || EmojiPaletteError::InvalidEntry(entry.to_owned());
+
+    let (emoji, color) = entry.split_once('=').ok_or_else(invalid)?;
+
+    let mut chars = emoji.trim().chars();
+    let (Some(emoji), None) = (chars.next(), chars.next()) else {
+        return Err(invalid());
+    };
+
+    Ok((emoji, parse_hex_color(color).ok_or_else(invalid)?))
+}
+
+/// Error caused by invalid [`EmojiPalette`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EmojiPaletteError {
+    /// Palette has no emoji
+    Empty,
+    /// Entry isn't a single character emoji and hex color, like `🟥=#dd2e44`
+    InvalidEntry(String),
+}
+
+impl error::Error for EmojiPaletteError {}
+
+impl fmt::Display for EmojiPaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmojiPaletteError::Empty => write!(f, "emoji palette is empty"),
+            EmojiPaletteError::InvalidEntry(entry) => write!(
+                f,
+                "invalid emoji palette entry `{}`, expected emoji and hex color, like `🟥=#dd2e44`",
+                entry
+            ),
+        }
+    }
+}
+
+/// Allows to render your images as mosaic of emoji
+pub trait EmojiArtConverter {
+    /// Convert image into mosaic of emoji with the closest colors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::emoji::{EMOJI_FONT_RATIO, EmojiArtConverter, EmojiArtConverterOptions},
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(Some(32), None, EMOJI_FONT_RATIO, FilterType::Triangle)
+    ///     .emoji_art(&EmojiArtConverterOptions::default())?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```
+    /// use image::{Rgb, RgbImage};
+    /// use tapciify::renderers::emoji::{EmojiArtConverter, EmojiArtConverterOptions};
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let colors = [[255, 0, 0], [0, 128, 0], [10, 10, 10], [250, 250, 250]];
+    /// let img = RgbImage::from_fn(4, 1, |x, _| Rgb(colors[x as usize]));
+    /// let result = img.emoji_art(&EmojiArtConverterOptions::default())?;
+    ///
+    /// assert_eq!(result.to_string(), "🟥🟩⬛⬜");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn emoji_art(&self, options: &EmojiArtConverterOptions) -> Result<AsciiArt, SizeError>;
+}
+
+impl EmojiArtConverter for image::DynamicImage {
+    fn emoji_art(&self, options: &EmojiArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        self.to_rgba8().emoji_art(options)
+    }
+}
+
+impl<P, Container> EmojiArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn emoji_art(&self, options: &EmojiArtConverterOptions) -> Result<AsciiArt, SizeError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(SizeError);
+        }
+
+        let palette = options
+            .palette
+            .emoji()
+            .iter()
+            .map(|(emoji, color)| (*emoji, rgb_to_lab(*color)))
+            .collect::<Vec<(char, [f32; 3])>>();
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..width * height).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..width * height;
+
+        let characters = iter
+            .map(|i| {
+                self.get_pixel(i % width, i / width)
+                    .to_raw_ascii_art_pixel(' ')
+            })
+            .map(|mut pixel| {
+                let lab = rgb_to_lab([pixel.r, pixel.g, pixel.b]);
+
+                pixel.character = palette
+                    .iter()
+                    .min_by(|(_, a), (_, b)| {
+                        lab_distance(*a, lab).total_cmp(&lab_distance(*b, lab))
+                    })
+                    .map_or(' ', |(emoji, _)| *emoji);
+
+                pixel
+            })
+            .collect();
+
+        Ok(AsciiArt::new(characters, width, height, ColorMode::None))
+    }
+}
+
+/// Squared euclidean distance between CIELAB colors (CIE76)
+fn lab_distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+/// Options for [`EmojiArtConverter::emoji_art`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct EmojiArtConverterOptions {
+    /// Emoji to pick from
+    pub palette: EmojiPalette,
+}
@@ -0,0 +1,515 @@
+//! Converting images to ASCII art by matching shapes of glyphs
+//!
+//! Every character cell covers a block of pixels, which is compared with rasterised glyphs
+//! of ASCII string using structural similarity (SSIM). So edges and lines get characters
+//! like `/`, `|` and `_`, not only the ones having the same density
+
+use std::{error, fmt};
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::{AsciiStringError, SizeError},
+    utils::{color::CellColor, lightness::LightnessModel, resize::DEFAULT_FONT_RATIO},
+};
+
+/// Default glyphs, having lines of every direction besides density ramp
+pub const DEFAULT_GLYPH_STRING: &str = " .,:;'`\"-_=+*^~<>()[]/\\|!?%#@";
+
+/// Default width of character cell in pixels
+pub const DEFAULT_GLYPH_CELL_WIDTH: u32 = 4;
+
+/// Default height of character cell in pixels
+pub const DEFAULT_GLYPH_CELL_HEIGHT: u32 = 8;
+
+/// Stabilizes luminance part of SSIM for dark cells
+const SSIM_C1: f32 = 0.01;
+
+/// Stabilizes structure part of SSIM for flat cells
+const SSIM_C2: f32 = 0.1;
+
+/// Rasterised glyph, scaled to the size of character cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphBitmap {
+    /// Character of glyph
+    pub character: char,
+    /// Ink coverage of every pixel (from 0.0 to 1.0), row by row
+    pub coverage: Vec<f32>,
+}
+
+impl GlyphBitmap {
+    /// Rasterise glyph from the built-in 8x8 bitmap font
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::glyph::GlyphBitmap;
+    ///
+    /// let glyph = GlyphBitmap::builtin('_', 4, 8).unwrap();
+    ///
+    /// assert_eq!(glyph.coverage[0..4], [0.0; 4]);
+    /// assert_eq!(glyph.coverage[28..32], [1.0; 4]);
+    /// assert!(GlyphBitmap::builtin('😀', 4, 8).is_none());
+    /// ```
+    pub fn builtin(character: char, width: u32, height: u32) -> Option<GlyphBitmap> {
+        use font8x8::UnicodeFonts;
+
+        let rows = font8x8::BASIC_FONTS
+            .get(character)
+            .or_else(|| font8x8::LATIN_FONTS.get(character))
+            .or_else(|| font8x8::GREEK_FONTS.get(character))
+            .or_else(|| font8x8::BLOCK_FONTS.get(character))
+            .or_else(|| font8x8::BOX_FONTS.get(character))
+            .or_else(|| font8x8::HIRAGANA_FONTS.get(character))
+            .or_else(|| font8x8::MISC_FONTS.get(character))?;
+
+        // Every bit is a pixel, the lowest one is on the left
+        let bitmap = (0..64)
+            .map(|i| ((rows[i / 8] >> (i % 8)) & 1) as f32)
+            .collect::<Vec<f32>>();
+
+        Some(GlyphBitmap {
+            character,
+            coverage: scale_coverage(&bitmap, 8, 8, width, height),
+        })
+    }
+}
+
+/// Glyphs of ASCII string, rasterised to the same cell size
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphSet {
+    /// Width of character cell in pixels
+    pub width: u32,
+    /// Height of character cell in pixels
+    pub height: u32,
+    /// Glyphs, which can be used in ASCII art
+    pub glyphs: Vec<GlyphBitmap>,
+    /// Characters of ASCII string, which font has no glyphs for
+    pub missing: Vec<char>,
+}
+
+impl Default for GlyphSet {
+    fn default() -> GlyphSet {
+        GlyphSet::builtin(
+            DEFAULT_GLYPH_STRING,
+            DEFAULT_GLYPH_CELL_WIDTH,
+            DEFAULT_GLYPH_CELL_HEIGHT,
+        )
+        .expect("built-in font has every character of default glyph string")
+    }
+}
+
+impl GlyphSet {
+    /// Rasterise characters of ASCII string using the built-in 8x8 bitmap font
+    ///
+    /// Characters missing in the font are collected into [`GlyphSet::missing`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::glyph::GlyphSet;
+    ///
+    /// let glyphs = GlyphSet::builtin(" /\\|_-😀", 4, 8).unwrap();
+    /// assert_eq!(glyphs.glyphs.len(), 6);
+    /// assert_eq!(glyphs.missing, vec!['😀']);
+    ///
+    /// assert!(GlyphSet::builtin("😀", 4, 8).is_err());
+    /// ```
+    pub fn builtin(ascii_string: &str, width: u32, height: u32) -> Result<GlyphSet, GlyphSetError> {
+        let mut glyphs = vec![];
+        let mut missing = vec![];
+
+        for character in ascii_string.chars() {
+            match GlyphBitmap::builtin(character, width, height) {
+                Some(glyph) => glyphs.push(glyph),
+                None => missing.push(character),
+            }
+        }
+
+        Ok(GlyphSet {
+            missing,
+            ..GlyphSet::new(glyphs, width, height)?
+        })
+    }
+
+    /// Rasterise characters of ASCII string using TrueType or OpenType font
+    ///
+    /// Font is expected to be monospace, glyphs are stretched to fill the cell
+    /// from ascent to descent, and from the left side to advance
+    #[cfg(feature = "glyph-ttf")]
+    pub fn from_ttf(
+        font_data: &[u8],
+        ascii_string: &str,
+        width: u32,
+        height: u32,
+    ) -> Result<GlyphSet, GlyphSetError> {
+        use ab_glyph::{Font, FontRef, PxScale, ScaleFont, point};
+
+        let font = FontRef::try_from_slice(font_data).map_err(|_| GlyphSetError::InvalidFont)?;
+
+        // Fit line height and advance of 'M' into the cell
+        let unscaled = font.as_scaled(PxScale::from(height as f32));
+        let advance = unscaled.h_advance(font.glyph_id('M')).max(1.0);
+        let scale = PxScale {
+            x: height as f32 * width as f32 / advance,
+            y: height as f32 * height as f32 / unscaled.height().max(1.0),
+        };
+        let ascent = font.as_scaled(scale).ascent();
+
+        let (characters, missing) = ascii_string.chars().partition::<Vec<char>, _>(|character| {
+            *character == ' ' || font.glyph_id(*character).0 != 0
+        });
+
+        let glyphs = characters
+            .into_iter()
+            .map(|character| {
+                let mut coverage = vec![0.0; (width * height) as usize];
+
+                let glyph = font
+                    .glyph_id(character)
+                    .with_scale_and_position(scale, point(0.0, ascent));
+
+                if let Some(outlined) = font.outline_glyph(glyph) {
+                    let bounds = outlined.px_bounds();
+
+                    outlined.draw(|x, y, value| {
+                        let x = x as i32 + bounds.min.x as i32;
+                        let y = y as i32 + bounds.min.y as i32;
+
+                        if (0..width as i32).contains(&x) && (0..height as i32).contains(&y) {
+                            coverage[(y as u32 * width + x as u32) as usize] = value.min(1.0);
+                        }
+                    });
+                }
+
+                GlyphBitmap {
+                    character,
+                    coverage,
+                }
+            })
+            .collect();
+
+        Ok(GlyphSet {
+            missing,
+            ..GlyphSet::new(glyphs, width, height)?
+        })
+    }
+
+    /// Make set from already rasterised glyphs
+    pub fn new(
+        glyphs: Vec<GlyphBitmap>,
+        width: u32,
+        height: u32,
+    ) -> Result<GlyphSet, GlyphSetError> {
+        if glyphs.is_empty() {
+            return Err(GlyphSetError::AsciiStringError(AsciiStringError));
+        }
+
+        Ok(GlyphSet {
+            width,
+            height,
+            glyphs,
+            missing: vec![],
+        })
+    }
+
+    /// Aspect ratio to use for resizing, when every character covers cell of pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::glyph::GlyphSet;
+    ///
+    /// assert_eq!(GlyphSet::default().font_ratio(), 11.0 / 12.0);
+    /// ```
+    pub fn font_ratio(&self) -> f64 {
+        DEFAULT_FONT_RATIO * self.height as f64 / self.width as f64
+    }
+}
+
+/// Error caused by [`GlyphSet`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GlyphSetError {
+    /// None of the characters of ASCII string has glyph
+    AsciiStringError(AsciiStringError),
+    /// Font can't be parsed
+    #[cfg(feature = "glyph-ttf")]
+    InvalidFont,
+}
+
+impl error::Error for GlyphSetError {}
+
+impl fmt::Display for GlyphSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlyphSetError::AsciiStringError(err) => {
+                write!(f, "no glyphs for ASCII string: {}", err)
+            }
+            #[cfg(feature = "glyph-ttf")]
+            GlyphSetError::InvalidFont => write!(f, "font can't be parsed"),
+        }
+    }
+}
+
+impl From<AsciiStringError> for GlyphSetError {
+    fn from(err: AsciiStringError) -> GlyphSetError {
+        GlyphSetError::AsciiStringError(err)
+    }
+}
+
+/// Allows to render your images by matching shapes of glyphs
+pub trait GlyphArtConverter {
+    /// Convert image into ASCII art, picking glyph most similar to every cell
+    ///
+    /// Image should be resized with [`GlyphSet::font_ratio`],
+    /// every [`GlyphSet::width`] x [`GlyphSet::height`] pixels become one character
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::glyph::{GlyphArtConverter, GlyphArtConverterOptions},
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    /// let options = GlyphArtConverterOptions::default();
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(
+    ///         Some(64 * options.glyphs.width),
+    ///         None,
+    ///         options.glyphs.font_ratio(),
+    ///         FilterType::Triangle,
+    ///     )
+    ///     .glyph_art(&options)?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Lines get characters of the same shape:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::renderers::glyph::{GlyphArtConverter, GlyphArtConverterOptions, GlyphBitmap};
+    ///
+    /// # fn main() -> Result<(), tapciify::prelude::AsciiArtConverterError> {
+    /// let options = GlyphArtConverterOptions::default();
+    ///
+    /// for character in ['/', '\\', '_', '-', '|'] {
+    ///     let glyph = GlyphBitmap::builtin(character, 4, 8).unwrap();
+    ///     let img = GrayImage::from_fn(4, 8, |x, y| {
+    ///         Luma([(glyph.coverage[(y * 4 + x) as usize] * 255.0) as u8])
+    ///     });
+    ///
+    ///     assert_eq!(img.glyph_art(&options)?.to_string(), character.to_string());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn glyph_art(
+        &self,
+        options: &GlyphArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError>;
+}
+
+impl GlyphArtConverter for image::DynamicImage {
+    fn glyph_art(
+        &self,
+        options: &GlyphArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        self.to_rgba8().glyph_art(options)
+    }
+}
+
+impl<P, Container> GlyphArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn glyph_art(
+        &self,
+        options: &GlyphArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        let width = self.width();
+        let height = self.height();
+        let glyphs = &options.glyphs;
+
+        if width == 0 || height == 0 || glyphs.width == 0 || glyphs.height == 0 {
+            return Err(AsciiArtConverterError::SizeError(SizeError));
+        }
+        if glyphs.glyphs.is_empty() {
+            return Err(AsciiArtConverterError::AsciiStringError(AsciiStringError));
+        }
+
+        // Densest glyph represents the lightest cell
+        let max_mean = glyphs
+            .glyphs
+            .iter()
+            .map(|glyph| mean(&glyph.coverage))
+            .fold(0.0, f32::max)
+            .max(f32::EPSILON);
+        let glyph_stats = glyphs
+            .glyphs
+            .iter()
+            .map(|glyph| {
+                let lightness = glyph
+                    .coverage
+                    .iter()
+                    .map(|coverage| coverage / max_mean)
+                    .collect::<Vec<f32>>();
+                let (glyph_mean, glyph_variance) = mean_variance(&lightness);
+
+                (lightness, glyph_mean, glyph_variance)
+            })
+            .collect::<Vec<(Vec<f32>, f32, f32)>>();
+
+        // Partial cells on the right and bottom edges are padded with dark pixels
+        let glyph_width = width.div_ceil(glyphs.width);
+        let glyph_height = height.div_ceil(glyphs.height);
+        let total_chars = glyph_width * glyph_height;
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..total_chars).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..total_chars;
+
+        let characters = iter
+            .map(|i| {
+                let x = (i % glyph_width) * glyphs.width;
+                let y = (i / glyph_width) * glyphs.height;
+                (x, y)
+            })
+            .map(|(x, y)| {
+                let cell = (0..glyphs.width * glyphs.height)
+                    .map(|i| (x + i % glyphs.width, y + i / glyphs.width))
+                    .map(|(x, y)| (x < width && y < height).then(|| self.get_pixel(x, y)))
+                    .collect::<Vec<Option<&P>>>();
+
+                let lightness = cell
+                    .iter()
+                    .map(|pixel| {
+                        pixel.map_or(0.0, |pixel| pixel.lightness_with(options.lightness_model))
+                    })
+                    .collect::<Vec<f32>>();
+                let (cell_mean, cell_variance) = mean_variance(&lightness);
+
+                let (index, _) = glyph_stats
+                    .iter()
+                    .map(|(glyph, glyph_mean, glyph_variance)| {
+                        let covariance = lightness
+                            .iter()
+                            .zip(glyph)
+                            .map(|(a, b)| (a - cell_mean) * (b - glyph_mean))
+                            .sum::<f32>()
+                            / lightness.len() as f32;
+
+                        ((2.0 * cell_mean * glyph_mean + SSIM_C1) * (2.0 * covariance + SSIM_C2))
+                            / ((cell_mean.powi(2) + glyph_mean.powi(2) + SSIM_C1)
+                                * (cell_variance + glyph_variance + SSIM_C2))
+                    })
+                    .enumerate()
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                    .unwrap_or((0, 0.0));
+                let glyph = &glyphs.glyphs[index];
+
+                let pixels = cell
+                    .iter()
+                    .zip(&glyph.coverage)
+                    .filter_map(|(pixel, coverage)| {
+                        pixel.map(|pixel| {
+                            let pixel = pixel.to_raw_ascii_art_pixel(glyph.character);
+                            ([pixel.r, pixel.g, pixel.b, pixel.a], *coverage > 0.5)
+                        })
+                    })
+                    .collect::<Vec<([u8; 4], bool)>>();
+                let [r, g, b, a] = options.cell_color.pick(&pixels);
+
+                AsciiArtPixel {
+                    character: glyph.character,
+                    r,
+                    g,
+                    b,
+                    a,
+                    background: None,
+                    grapheme: None,
+                }
+            })
+            .collect();
+
+        Ok(AsciiArt::new(
+            characters,
+            glyph_width,
+            glyph_height,
+            options.color_mode,
+        ))
+    }
+}
+
+/// Options for [`GlyphArtConverter::glyph_art`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GlyphArtConverterOptions {
+    /// Glyphs to pick characters from
+    pub glyphs: GlyphSet,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of picking color of character from its cell
+    pub cell_color: CellColor,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+/// Resize coverage bitmap using area averaging
+fn scale_coverage(
+    bitmap: &[f32],
+    width: u32,
+    height: u32,
+    new_width: u32,
+    new_height: u32,
+) -> Vec<f32> {
+    (0..new_width * new_height)
+        .map(|i| (i % new_width, i / new_width))
+        .map(|(x, y)| {
+            // Source pixels covered by target pixel
+            let (x_start, x_end) = (x * width / new_width, ((x + 1) * width).div_ceil(new_width));
+            let (y_start, y_end) = (
+                y * height / new_height,
+                ((y + 1) * height).div_ceil(new_height),
+            );
+
+            let values = (y_start..y_end)
+                .flat_map(|y| (x_start..x_end).map(move |x| bitmap[(y * width + x) as usize]))
+                .collect::<Vec<f32>>();
+
+            mean(&values)
+        })
+        .collect()
+}
+
+/// Mean of values
+fn mean(values: &[f32]) -> f32 {
+    values.iter().sum::<f32>() / values.len().max(1) as f32
+}
+
+/// Mean and variance of values
+fn mean_variance(values: &[f32]) -> (f32, f32) {
+    let mean = mean(values);
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f32>()
+        / values.len().max(1) as f32;
+
+    (mean, variance)
+}
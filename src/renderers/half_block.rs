@@ -0,0 +1,236 @@
+//! Converting images to ASCII art using half block characters
+//!
+//! Every character cell holds two vertical pixels: the top one is drawn with
+//! the foreground color of `▀`, the bottom one with the background color.
+//! Transparent halves are left unpainted
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::SizeError,
+    utils::{
+        alpha::ALPHA_THRESHOLD,
+        lightness::LightnessModel,
+        threshold::{DEFAULT_THRESHOLD, ThresholdPixel},
+    },
+};
+
+/// Half block characters aspect ratio
+pub const DEFAULT_HALF_BLOCK_FONT_RATIO: f64 = 11.0 / 12.0;
+
+/// Upper half block, colored cells are rendered with it, unless top pixel is transparent
+pub const UPPER_HALF_BLOCK: char = '▀';
+
+/// Lower half block, used for colored cells with only bottom pixel visible
+pub const LOWER_HALF_BLOCK: char = '▄';
+
+/// Convert pair of vertical pixels into half block character
+///
+/// Used for uncolored output, where background color can't be set
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::half_block::half_block_character;
+///
+/// assert_eq!(half_block_character(false, false), ' ');
+/// assert_eq!(half_block_character(true, false), '▀');
+/// assert_eq!(half_block_character(false, true), '▄');
+/// assert_eq!(half_block_character(true, true), '█');
+/// ```
+pub fn half_block_character(top: bool, bottom: bool) -> char {
+    match (top, bottom) {
+        (false, false) => ' ',
+        (true, false) => UPPER_HALF_BLOCK,
+        (false, true) => LOWER_HALF_BLOCK,
+        (true, true) => '█',
+    }
+}
+
+/// Allows to render your images using half block characters
+pub trait HalfBlockArtConverter {
+    /// Convert image into ASCII art using half block characters
+    ///
+    /// Colored output uses `▀` with top pixel as foreground and bottom pixel as background,
+    /// or `▄` with bottom pixel as foreground when top pixel is transparent.
+    /// Uncolored output picks one of ` `, `▀`, `▄` and `█` using threshold
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::half_block::{
+    ///         DEFAULT_HALF_BLOCK_FONT_RATIO, HalfBlockArtConverter, HalfBlockArtConverterOptions,
+    ///     },
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(
+    ///         Some(64),
+    ///         None,
+    ///         DEFAULT_HALF_BLOCK_FONT_RATIO,
+    ///         FilterType::Triangle,
+    ///     )
+    ///     .half_block_art(&HalfBlockArtConverterOptions {
+    ///         color_mode: ColorMode::TrueColor,
+    ///         ..Default::default()
+    ///     })?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Odd heights are padded, so the last row has no background:
+    ///
+    /// ```
+    /// use image::{Rgb, RgbImage};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::half_block::{HalfBlockArtConverter, HalfBlockArtConverterOptions},
+    /// };
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let img = RgbImage::from_fn(1, 3, |_, y| Rgb([y as u8 * 100, 0, 0]));
+    /// let result = img.half_block_art(&HalfBlockArtConverterOptions {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(result.height, 2);
+    /// assert_eq!((result.characters[0].r, result.characters[0].background), (0, Some(Rgb([100, 0, 0]))));
+    /// assert_eq!((result.characters[1].r, result.characters[1].background), (200, None));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Transparent halves get no color:
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::half_block::{HalfBlockArtConverter, HalfBlockArtConverterOptions},
+    /// };
+    ///
+    /// # fn main() -> Result<(), tapciify::renderers::ascii::SizeError> {
+    /// let img = RgbaImage::from_fn(2, 2, |x, y| match (x, y) {
+    ///     (0, 1) | (1, 0) => Rgba([0, 0, 0, 0]),
+    ///     _ => Rgba([255, 0, 0, 255]),
+    /// });
+    /// let result = img.half_block_art(&HalfBlockArtConverterOptions {
+    ///     color_mode: ColorMode::TrueColor,
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// let [opaque_top, opaque_bottom] = [&result.characters[0], &result.characters[1]];
+    /// assert_eq!((opaque_top.character, opaque_top.background), ('▀', None));
+    /// assert_eq!((opaque_bottom.character, opaque_bottom.background), ('▄', None));
+    /// assert_eq!((opaque_bottom.r, opaque_bottom.a), (255, 255));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn half_block_art(&self, options: &HalfBlockArtConverterOptions)
+    -> Result<AsciiArt, SizeError>;
+}
+
+impl HalfBlockArtConverter for image::DynamicImage {
+    fn half_block_art(
+        &self,
+        options: &HalfBlockArtConverterOptions,
+    ) -> Result<AsciiArt, SizeError> {
+        self.to_rgba8().half_block_art(options)
+    }
+}
+
+impl<P, Container> HalfBlockArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + ThresholdPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn half_block_art(
+        &self,
+        options: &HalfBlockArtConverterOptions,
+    ) -> Result<AsciiArt, SizeError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(SizeError);
+        }
+
+        let half_block_height = height.div_ceil(2);
+        let total_chars = width * half_block_height;
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..total_chars).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..total_chars;
+
+        let characters = iter
+            .map(|i| (i % width, (i / width) * 2))
+            .map(|(x, y)| {
+                let top = self.get_pixel(x, y);
+                // Bottom pixel is missing on the last row of odd heights
+                let bottom = (y + 1 < height).then(|| self.get_pixel(x, y + 1));
+
+                if !options.color_mode.is_colored() {
+                    let lit = |pixel: &P| {
+                        pixel.threshold_pixel_with(DEFAULT_THRESHOLD, options.lightness_model)
+                    };
+
+                    return top.to_raw_ascii_art_pixel(half_block_character(
+                        lit(top),
+                        bottom.is_some_and(lit),
+                    ));
+                }
+
+                let top = top.to_raw_ascii_art_pixel(UPPER_HALF_BLOCK);
+                let Some(bottom) = bottom
+                    .map(|bottom| bottom.to_raw_ascii_art_pixel(LOWER_HALF_BLOCK))
+                    .filter(|bottom| bottom.a >= ALPHA_THRESHOLD)
+                else {
+                    return top;
+                };
+
+                // Only bottom half is painted, top one keeps terminal background
+                if top.a < ALPHA_THRESHOLD {
+                    return bottom;
+                }
+
+                AsciiArtPixel {
+                    background: Some(image::Rgb([bottom.r, bottom.g, bottom.b])),
+                    ..top
+                }
+            })
+            .collect();
+
+        Ok(AsciiArt::new(
+            characters,
+            width,
+            half_block_height,
+            options.color_mode,
+        ))
+    }
+}
+
+/// Options for [`HalfBlockArtConverter::half_block_art`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HalfBlockArtConverterOptions {
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of calculating lightness of pixels for uncolored output
+    pub lightness_model: LightnessModel,
+}
@@ -0,0 +1,257 @@
+//! Converting images to ASCII art simulating halftone print screen
+//!
+//! Characters are covered by screen of round dots, rotated by angle. Dots grow with
+//! lightness, and are drawn with glyphs of increasing size from their edges to centers
+
+use image::{ImageBuffer, Pixel};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    prelude::*,
+    renderers::ascii::{Ramp, SizeError},
+    utils::{
+        lightness::LightnessModel,
+        resize::{DEFAULT_FONT_RATIO, cell_font_ratio},
+    },
+};
+
+/// Default dot glyphs, from the smallest to the largest
+pub const DEFAULT_HALFTONE_DOTS: &str = "·•●⬤";
+
+/// Default angle of screen in degrees
+pub const DEFAULT_SCREEN_ANGLE: f32 = 45.0;
+
+/// Default distance between dots of screen in characters
+pub const DEFAULT_SCREEN_PERIOD: f32 = 4.0;
+
+/// Offsets of cyan, magenta, yellow and key screen angles, to avoid moiré
+const CMYK_ANGLE_OFFSETS: [f32; 4] = [-30.0, 30.0, -45.0, 0.0];
+
+/// Colors of cyan, magenta, yellow and key inks
+const CMYK_INKS: [[u8; 3]; 4] = [[0, 255, 255], [255, 0, 255], [255, 255, 0], [0, 0, 0]];
+
+/// Strength of halftone dot at the character, from coverage of dot
+///
+/// Coordinates are in characters, with `y` scaled by font ratio to make dots round.
+/// Non positive values mean that character is out of dot
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::halftone::dot_strength;
+///
+/// // Center of dot
+/// assert_eq!(dot_strength(0.75, 0.5, 0.5, 0.0, 1.0), 0.75);
+/// // Corner between dots is covered only by full coverage
+/// assert!(dot_strength(0.75, 0.0, 0.0, 0.0, 1.0) <= 0.0);
+/// ```
+pub fn dot_strength(coverage: f32, x: f32, y: f32, angle: f32, period: f32) -> f32 {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let u = (x * cos + y * sin) / period;
+    let v = (y * cos - x * sin) / period;
+
+    // Squared distance to center of screen cell, 0.0 at center and 1.0 at corners
+    let spot = 2.0 * ((u - u.floor() - 0.5).powi(2) + (v - v.floor() - 0.5).powi(2));
+
+    coverage - spot
+}
+
+/// Split color into cyan, magenta, yellow and key inks amounts
+fn cmyk(r: u8, g: u8, b: u8) -> [f32; 4] {
+    let [r, g, b] = [r, g, b].map(|channel| channel as f32 / 255.0);
+    let key = 1.0 - r.max(g).max(b);
+
+    if key >= 1.0 {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+
+    let [cyan, magenta, yellow] = [r, g, b].map(|channel| (1.0 - channel - key) / (1.0 - key));
+
+    [cyan, magenta, yellow, key]
+}
+
+/// Allows to render your images as halftone print
+pub trait HalftoneArtConverter {
+    /// Convert image into ASCII art using halftone dots
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::error::Error;
+    ///
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::halftone::{HalftoneArtConverter, HalftoneArtConverterOptions},
+    ///     utils::resize::DEFAULT_FONT_RATIO,
+    /// };
+    ///
+    /// # use image::imageops::FilterType;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let img = image::open("./assets/examples/ferris.webp")?;
+    ///
+    /// let result = img
+    ///     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+    ///     .halftone_art(&HalftoneArtConverterOptions::default())?;
+    ///
+    /// println!("{}", result);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Screen with one dot per character picks glyphs by lightness:
+    ///
+    /// ```
+    /// use image::{GrayImage, Luma};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     renderers::halftone::{HalftoneArtConverter, HalftoneArtConverterOptions},
+    /// };
+    ///
+    /// # fn main() -> Result<(), AsciiArtConverterError> {
+    /// let img = GrayImage::from_fn(4, 1, |x, _| Luma([[0, 64, 160, 255][x as usize]]));
+    /// let result = img.halftone_art(&HalftoneArtConverterOptions {
+    ///     dots: "·•●".to_owned(),
+    ///     angle: 0.0,
+    ///     period: 1.0,
+    ///     font_ratio: 1.0,
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(result.to_string(), " ·•●");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn halftone_art(
+        &self,
+        options: &HalftoneArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError>;
+}
+
+impl HalftoneArtConverter for image::DynamicImage {
+    fn halftone_art(
+        &self,
+        options: &HalftoneArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        self.to_rgba8().halftone_art(options)
+    }
+}
+
+impl<P, Container> HalftoneArtConverter for ImageBuffer<P, Container>
+where
+    P: Pixel + ToAsciiArtPixel + Sync,
+    P::Subpixel: Sync,
+    Container: std::ops::Deref<Target = [P::Subpixel]> + Sync,
+{
+    fn halftone_art(
+        &self,
+        options: &HalftoneArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiArtConverterError> {
+        let width = self.width();
+        let height = self.height();
+
+        if width == 0 || height == 0 {
+            return Err(AsciiArtConverterError::SizeError(SizeError));
+        }
+
+        let dots = Ramp::new(&options.dots)?;
+        let blank = " ".repeat(dots.width() as usize);
+        // Wide dots take several columns of font
+        let font_ratio = cell_font_ratio(options.font_ratio, dots.width());
+
+        // Dots smaller than character can't be drawn
+        let period = options.period.max(1.0);
+        let separation = options.cmyk && options.color_mode.is_colored();
+
+        #[cfg(feature = "rayon")]
+        let iter = (0..width * height).into_par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let iter = 0..width * height;
+
+        let characters = iter
+            .map(|i| (i % width, i / width))
+            .map(|(x, y)| {
+                let pixel = self.get_pixel(x, y);
+                let mut ascii_pixel = pixel.to_raw_ascii_art_pixel(' ');
+
+                let x = x as f32 + 0.5;
+                let y = (y as f32 + 0.5) / font_ratio as f32;
+
+                // Every ink has its own screen, the strongest dot is drawn
+                let strength = match separation {
+                    true => {
+                        let inks = cmyk(ascii_pixel.r, ascii_pixel.g, ascii_pixel.b);
+                        let (strength, ink) = (0..4)
+                            .map(|i| {
+                                let angle = options.angle + CMYK_ANGLE_OFFSETS[i];
+                                (dot_strength(inks[i], x, y, angle, period), CMYK_INKS[i])
+                            })
+                            .fold((f32::MIN, [0; 3]), |a, b| if b.0 > a.0 { b } else { a });
+
+                        [ascii_pixel.r, ascii_pixel.g, ascii_pixel.b] = ink;
+                        strength
+                    }
+                    false => {
+                        let lightness = pixel.lightness_with(options.lightness_model);
+                        dot_strength(lightness, x, y, options.angle, period)
+                    }
+                };
+
+                let dot = match strength > 0.0 {
+                    true => {
+                        let count = dots.graphemes().len();
+                        let index = ((strength * count as f32).ceil() as usize).clamp(1, count);
+                        &dots.graphemes()[index - 1]
+                    }
+                    false => &blank,
+                };
+                let dot_pixel = pixel.to_grapheme_ascii_art_pixel(dot);
+
+                AsciiArtPixel {
+                    character: dot_pixel.character,
+                    grapheme: dot_pixel.grapheme,
+                    ..ascii_pixel
+                }
+            })
+            .collect();
+
+        Ok(AsciiArt::new(characters, width, height, options.color_mode))
+    }
+}
+
+/// Options for [`HalftoneArtConverter::halftone_art`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalftoneArtConverterOptions {
+    /// Dot glyphs, from the smallest to the largest
+    pub dots: String,
+    /// Angle of screen in degrees
+    pub angle: f32,
+    /// Distance between dots of screen in characters, at least 1.0
+    pub period: f32,
+    /// Font ratio of characters, used to make dots round
+    pub font_ratio: f64,
+    /// Separate colors into cyan, magenta, yellow and key screens, like in print
+    ///
+    /// Inks are drawn on dark parts of image, so it suits light terminal background.
+    /// Used only with colored [`ColorMode`]
+    pub cmyk: bool,
+    /// Color depth of [`AsciiArt`]
+    pub color_mode: ColorMode,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
+}
+
+impl Default for HalftoneArtConverterOptions {
+    fn default() -> HalftoneArtConverterOptions {
+        HalftoneArtConverterOptions {
+            dots: DEFAULT_HALFTONE_DOTS.to_owned(),
+            angle: DEFAULT_SCREEN_ANGLE,
+            period: DEFAULT_SCREEN_PERIOD,
+            font_ratio: DEFAULT_FONT_RATIO,
+            cmyk: false,
+            color_mode: ColorMode::None,
+            lightness_model: LightnessModel::default(),
+        }
+    }
+}
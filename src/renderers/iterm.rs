@@ -0,0 +1,103 @@
+//! Showing images using iTerm2 inline images protocol (OSC 1337)
+//!
+//! Supported by iTerm2, WezTerm, Konsole, mintty and some other terminals
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use tapciify::{
+//!     prelude::*,
+//!     renderers::iterm::{ItermImageConverter, ItermImageOptions},
+//! };
+//!
+//! # use image::imageops::FilterType;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//!
+//! let result = img
+//!     .resize_custom_ratio(Some(320), None, 1.0, FilterType::Triangle)
+//!     .iterm_image(&ItermImageOptions {
+//!         columns: Some(64),
+//!         ..Default::default()
+//!     })?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Cursor;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Convert image into iTerm2 inline image escape sequence
+pub trait ItermImageConverter {
+    /// Encode image as PNG and wrap it into OSC 1337 `File=` escape sequence
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::renderers::iterm::{ItermImageConverter, ItermImageOptions};
+    ///
+    /// # fn main() -> Result<(), image::ImageError> {
+    /// let img = RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255]));
+    /// let result = img.iterm_image(&ItermImageOptions {
+    ///     columns: Some(2),
+    ///     rows: Some(1),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert!(result.starts_with("\x1b]1337;File=inline=1;size="));
+    /// assert!(result.contains(";width=2;height=1;preserveAspectRatio=0:iVBORw0KGgo"));
+    /// assert!(result.ends_with('\x07'));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn iterm_image(&self, options: &ItermImageOptions) -> Result<String, image::ImageError>;
+}
+
+impl ItermImageConverter for image::DynamicImage {
+    fn iterm_image(&self, options: &ItermImageOptions) -> Result<String, image::ImageError> {
+        let mut png = Vec::new();
+        self.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?;
+
+        let mut arguments = format!("inline=1;size={}", png.len());
+
+        if let Some(columns) = options.columns {
+            arguments.push_str(&format!(";width={}", columns));
+        }
+        if let Some(rows) = options.rows {
+            arguments.push_str(&format!(";height={}", rows));
+        }
+        if !options.preserve_aspect_ratio {
+            arguments.push_str(";preserveAspectRatio=0");
+        }
+
+        Ok(format!(
+            "\x1b]1337;File={}:{}\x07",
+            arguments,
+            STANDARD.encode(png)
+        ))
+    }
+}
+
+impl ItermImageConverter for image::RgbaImage {
+    fn iterm_image(&self, options: &ItermImageOptions) -> Result<String, image::ImageError> {
+        image::DynamicImage::from(self.to_owned()).iterm_image(options)
+    }
+}
+
+/// Options for [`ItermImageConverter::iterm_image`]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ItermImageOptions {
+    /// Amount of columns to stretch image on
+    pub columns: Option<u32>,
+    /// Amount of rows to stretch image on
+    pub rows: Option<u32>,
+    /// Keep aspect ratio of the image, when both columns and rows are set
+    pub preserve_aspect_ratio: bool,
+}
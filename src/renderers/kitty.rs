@@ -0,0 +1,192 @@
+//! Showing images using kitty graphics protocol
+//!
+//! Supported by kitty, WezTerm, Konsole, Ghostty and some other terminals
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use tapciify::{
+//!     prelude::*,
+//!     renderers::kitty::{KittyGraphicsConverter, KittyGraphicsOptions},
+//! };
+//!
+//! # use image::imageops::FilterType;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//!
+//! let result = img
+//!     .resize_custom_ratio(Some(320), None, 1.0, FilterType::Triangle)
+//!     .kitty_graphics(&KittyGraphicsOptions {
+//!         columns: Some(64),
+//!         ..Default::default()
+//!     });
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// Maximal size of base64 payload in one escape sequence
+pub const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// Convert image into [`KittyGraphics`]
+pub trait KittyGraphicsConverter {
+    /// Convert image into [`KittyGraphics`], which can be printed to show image in terminal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::renderers::kitty::{KittyGraphicsConverter, KittyGraphicsOptions};
+    ///
+    /// let img = RgbaImage::from_pixel(1, 1, Rgba([255, 0, 0, 255]));
+    /// let result = img.kitty_graphics(&KittyGraphicsOptions::default());
+    ///
+    /// assert_eq!(
+    ///     result.to_string(),
+    ///     "\x1b_Ga=T,f=32,s=1,v=1,i=1,p=1,q=2,m=0;/wAA/w==\x1b\\"
+    /// );
+    /// ```
+    ///
+    /// Big images are split into chunks:
+    ///
+    /// ```
+    /// use image::RgbaImage;
+    /// use tapciify::renderers::kitty::{KittyGraphicsConverter, KittyGraphicsOptions};
+    ///
+    /// let img = RgbaImage::new(2048, 1);
+    /// let result = img.kitty_graphics(&KittyGraphicsOptions::default()).to_string();
+    ///
+    /// assert_eq!(result.matches("\x1b_G").count(), 3);
+    /// assert!(result.contains("\x1b_Gm=1;"));
+    /// assert!(result.contains("\x1b_Gm=0;"));
+    /// ```
+    fn kitty_graphics(&self, options: &KittyGraphicsOptions) -> KittyGraphics;
+}
+
+impl KittyGraphicsConverter for image::DynamicImage {
+    fn kitty_graphics(&self, options: &KittyGraphicsOptions) -> KittyGraphics {
+        self.to_rgba8().kitty_graphics(options)
+    }
+}
+
+impl KittyGraphicsConverter for image::RgbaImage {
+    fn kitty_graphics(&self, options: &KittyGraphicsOptions) -> KittyGraphics {
+        KittyGraphics {
+            data: STANDARD.encode(self.as_raw()),
+            width: self.width(),
+            height: self.height(),
+            options: options.to_owned(),
+        }
+    }
+}
+
+/// Options for [`KittyGraphicsConverter::kitty_graphics`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KittyGraphicsOptions {
+    /// Id of the image. Transmitting new image with the same id replaces the old one
+    pub image_id: u32,
+    /// Id of the placement. Placing image with the same id replaces the old placement
+    pub placement_id: Option<u32>,
+    /// Amount of columns to stretch image on
+    pub columns: Option<u32>,
+    /// Amount of rows to stretch image on
+    pub rows: Option<u32>,
+    /// Move cursor after the image, like after printing text
+    pub move_cursor: bool,
+}
+
+impl Default for KittyGraphicsOptions {
+    fn default() -> KittyGraphicsOptions {
+        KittyGraphicsOptions {
+            image_id: 1,
+            placement_id: Some(1),
+            columns: None,
+            rows: None,
+            move_cursor: true,
+        }
+    }
+}
+
+/// Image encoded for kitty graphics protocol
+///
+/// Use [`fmt::Display`] to get escape sequences
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct KittyGraphics {
+    /// Base64 encoded RGBA pixels
+    pub data: String,
+    /// Width of the image in pixels
+    pub width: u32,
+    /// Height of the image in pixels
+    pub height: u32,
+    /// Options used for transmitting and placing image
+    pub options: KittyGraphicsOptions,
+}
+
+impl KittyGraphics {
+    /// Control data of the first escape sequence
+    fn control_data(&self) -> String {
+        let mut control_data = format!(
+            "a=T,f=32,s={},v={},i={}",
+            self.width, self.height, self.options.image_id
+        );
+
+        if let Some(placement_id) = self.options.placement_id {
+            control_data.push_str(&format!(",p={}", placement_id));
+        }
+        if let Some(columns) = self.options.columns {
+            control_data.push_str(&format!(",c={}", columns));
+        }
+        if let Some(rows) = self.options.rows {
+            control_data.push_str(&format!(",r={}", rows));
+        }
+        if !self.options.move_cursor {
+            control_data.push_str(",C=1");
+        }
+
+        // Suppress terminal responses
+        control_data.push_str(",q=2");
+
+        control_data
+    }
+}
+
+impl fmt::Display for KittyGraphics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let chunks = self
+            .data
+            .as_bytes()
+            .chunks(KITTY_CHUNK_SIZE)
+            .collect::<Vec<&[u8]>>();
+
+        if chunks.is_empty() {
+            return write!(f, "\x1b_G{},m=0;\x1b\\", self.control_data());
+        }
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let more = (i + 1 < chunks.len()) as u8;
+            // Base64 is always valid UTF-8
+            let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+
+            match i {
+                0 => write!(
+                    f,
+                    "\x1b_G{},m={};{}\x1b\\",
+                    self.control_data(),
+                    more,
+                    chunk
+                )?,
+                _ => write!(f, "\x1b_Gm={};{}\x1b\\", more, chunk)?,
+            }
+        }
+
+        Ok(())
+    }
+}
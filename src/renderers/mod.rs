@@ -1,7 +1,38 @@
 pub mod ascii;
+pub mod renderer;
 
 #[cfg(feature = "background-string")]
 pub mod background_string;
 
+#[cfg(feature = "blocks")]
+pub mod blocks;
+
 #[cfg(feature = "braille")]
 pub mod braille;
+
+#[cfg(feature = "contour")]
+pub mod contour;
+
+#[cfg(feature = "edges")]
+pub mod edges;
+
+#[cfg(feature = "emoji")]
+pub mod emoji;
+
+#[cfg(feature = "glyph")]
+pub mod glyph;
+
+#[cfg(feature = "half-block")]
+pub mod half_block;
+
+#[cfg(feature = "halftone")]
+pub mod halftone;
+
+#[cfg(feature = "iterm")]
+pub mod iterm;
+
+#[cfg(feature = "kitty")]
+pub mod kitty;
+
+#[cfg(feature = "sixel")]
+pub mod sixel;
@@ -0,0 +1,466 @@
+//! Common interface of all renderers, so they can be selected at runtime
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use tapciify::{
+//!     prelude::*,
+//!     renderers::renderer::{RenderMode, Renderer},
+//! };
+//!
+//! # use image::imageops::FilterType;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//! let render_mode = RenderMode::Ascii(AsciiArtConverterOptions::default());
+//!
+//! let result = render_mode.render(&img.resize_custom_ratio(
+//!     Some(64),
+//!     None,
+//!     render_mode.font_ratio(),
+//!     FilterType::Triangle,
+//! ))?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt, sync::Arc};
+
+#[cfg(feature = "background-string")]
+use crate::renderers::ascii::DEFAULT_ASCII_STRING;
+#[cfg(any(feature = "background-string", feature = "edges", feature = "halftone"))]
+use crate::renderers::ascii::Ramp;
+#[cfg(feature = "glyph")]
+use crate::renderers::ascii::{AsciiStringError, SizeError};
+#[cfg(feature = "background-string")]
+use crate::renderers::background_string::{
+    BackgroundStringArtConverter, BackgroundStringArtConverterOptions,
+};
+#[cfg(feature = "blocks")]
+use crate::renderers::blocks::{BlockArtConverter, BlockArtConverterOptions, BlockKind};
+#[cfg(feature = "braille")]
+use crate::renderers::braille::{
+    BrailleArtConverter, BrailleArtConverterOptions, DEFAULT_BRAILLE_FONT_RATIO,
+};
+#[cfg(feature = "contour")]
+use crate::renderers::contour::{ContourArtConverter, ContourArtConverterOptions};
+#[cfg(feature = "edges")]
+use crate::renderers::edges::{EdgeArtConverter, EdgeArtConverterOptions};
+#[cfg(feature = "emoji")]
+use crate::renderers::emoji::{EMOJI_FONT_RATIO, EmojiArtConverter, EmojiArtConverterOptions};
+#[cfg(feature = "glyph")]
+use crate::renderers::glyph::{GlyphArtConverter, GlyphArtConverterOptions};
+#[cfg(feature = "half-block")]
+use crate::renderers::half_block::{
+    DEFAULT_HALF_BLOCK_FONT_RATIO, HalfBlockArtConverter, HalfBlockArtConverterOptions,
+};
+#[cfg(feature = "halftone")]
+use crate::renderers::halftone::{HalftoneArtConverter, HalftoneArtConverterOptions};
+#[cfg(feature = "emoji")]
+use crate::utils::resize::WIDE_CELL_COLUMNS;
+use crate::{
+    prelude::*,
+    renderers::ascii::ascii_string_width,
+    utils::resize::{DEFAULT_FONT_RATIO, cell_font_ratio},
+};
+
+/// Object safe renderer of images into [`AsciiArt`]
+///
+/// Implement it to use your own renderer with [`RenderMode::Custom`]
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use tapciify::{
+///     prelude::*,
+///     renderers::renderer::{RenderMode, Renderer},
+/// };
+///
+/// /// Renders every pixel as `#`
+/// #[derive(Debug)]
+/// struct HashRenderer;
+///
+/// impl Renderer for HashRenderer {
+///     fn name(&self) -> &str {
+///         "hash"
+///     }
+///
+///     fn render(&self, img: &image::DynamicImage) -> Result<AsciiArt, AsciiArtConverterError> {
+///         img.ascii_art(&AsciiArtConverterOptions {
+///             ascii_string: "#".to_owned(),
+///             ..Default::default()
+///         })
+///     }
+/// }
+///
+/// # fn main() -> Result<(), AsciiArtConverterError> {
+/// let render_mode = RenderMode::Custom(Arc::new(HashRenderer));
+/// let img = image::DynamicImage::new_rgb8(2, 1);
+///
+/// assert_eq!(render_mode.name(), "hash");
+/// assert_eq!(render_mode.render(&img)?.to_string(), "##");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Renderer: fmt::Debug + Send + Sync {
+    /// Name of renderer, used for selecting and listing it
+    fn name(&self) -> &str;
+
+    /// Short description of renderer, used for listing it
+    fn description(&self) -> &str {
+        ""
+    }
+
+    /// Terminal columns taken by every pixel, like 2 for double-width characters
+    fn cell_columns(&self) -> u32 {
+        1
+    }
+
+    /// Width / height ratio of characters produced by renderer, used for resizing images
+    fn font_ratio(&self) -> f64 {
+        cell_font_ratio(DEFAULT_FONT_RATIO, self.cell_columns())
+    }
+
+    /// Check options of renderer, before converting any images
+    fn validate(&self) -> Result<(), AsciiArtConverterError> {
+        Ok(())
+    }
+
+    /// Convert already resized image into [`AsciiArt`]
+    fn render(&self, img: &image::DynamicImage) -> Result<AsciiArt, AsciiArtConverterError>;
+}
+
+/// Built-in renderers with their options, and third-party ones
+#[derive(Debug, Clone)]
+pub enum RenderMode {
+    /// [`AsciiArtConverter::ascii_art`]
+    Ascii(AsciiArtConverterOptions),
+    /// [`BrailleArtConverter::braille_art`]
+    #[cfg(feature = "braille")]
+    Braille(BrailleArtConverterOptions),
+    /// [`BackgroundStringArtConverter::background_string_art`]
+    #[cfg(feature = "background-string")]
+    BackgroundString {
+        /// String to put on light pixels
+        string: String,
+        /// Options of renderer
+        options: BackgroundStringArtConverterOptions,
+    },
+    /// [`HalfBlockArtConverter::half_block_art`]
+    #[cfg(feature = "half-block")]
+    HalfBlock(HalfBlockArtConverterOptions),
+    /// [`BlockArtConverter::block_art`]
+    #[cfg(feature = "blocks")]
+    Blocks(BlockArtConverterOptions),
+    /// [`ContourArtConverter::contour_art`]
+    #[cfg(feature = "contour")]
+    Contour(ContourArtConverterOptions),
+    /// [`EdgeArtConverter::edge_art`]
+    #[cfg(feature = "edges")]
+    Edges(EdgeArtConverterOptions),
+    /// [`EmojiArtConverter::emoji_art`]
+    #[cfg(feature = "emoji")]
+    Emoji(EmojiArtConverterOptions),
+    /// [`GlyphArtConverter::glyph_art`]
+    #[cfg(feature = "glyph")]
+    Glyph(GlyphArtConverterOptions),
+    /// [`HalftoneArtConverter::halftone_art`]
+    #[cfg(feature = "halftone")]
+    Halftone(HalftoneArtConverterOptions),
+    /// Any other [`Renderer`]
+    Custom(Arc<dyn Renderer>),
+}
+
+impl Default for RenderMode {
+    fn default() -> RenderMode {
+        RenderMode::Ascii(AsciiArtConverterOptions::default())
+    }
+}
+
+impl RenderMode {
+    /// Built-in renderers enabled by features, with default options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::prelude::*;
+    ///
+    /// for render_mode in RenderMode::builtin() {
+    ///     println!("{:<20}{}", render_mode.name(), render_mode.description());
+    /// }
+    ///
+    /// assert_eq!(RenderMode::builtin()[0].name(), "ascii");
+    /// ```
+    pub fn builtin() -> Vec<RenderMode> {
+        vec![
+            RenderMode::default(),
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(BrailleArtConverterOptions::default()),
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString {
+                string: DEFAULT_ASCII_STRING.to_owned(),
+                options: BackgroundStringArtConverterOptions::default(),
+            },
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(HalfBlockArtConverterOptions::default()),
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(BlockArtConverterOptions {
+                kind: BlockKind::Quadrant,
+                ..Default::default()
+            }),
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(BlockArtConverterOptions {
+                kind: BlockKind::Sextant,
+                ..Default::default()
+            }),
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(ContourArtConverterOptions::default()),
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(EdgeArtConverterOptions::default()),
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(EmojiArtConverterOptions::default()),
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(GlyphArtConverterOptions::default()),
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(HalftoneArtConverterOptions::default()),
+        ]
+    }
+}
+
+impl PartialEq for RenderMode {
+    /// Custom renderers are equal only if they are the same object
+    fn eq(&self, other: &RenderMode) -> bool {
+        match (self, other) {
+            (RenderMode::Ascii(a), RenderMode::Ascii(b)) => a == b,
+            #[cfg(feature = "braille")]
+            (RenderMode::Braille(a), RenderMode::Braille(b)) => a == b,
+            #[cfg(feature = "background-string")]
+            (
+                RenderMode::BackgroundString { string, options },
+                RenderMode::BackgroundString {
+                    string: other_string,
+                    options: other_options,
+                },
+            ) => string == other_string && options == other_options,
+            #[cfg(feature = "half-block")]
+            (RenderMode::HalfBlock(a), RenderMode::HalfBlock(b)) => a == b,
+            #[cfg(feature = "blocks")]
+            (RenderMode::Blocks(a), RenderMode::Blocks(b)) => a == b,
+            #[cfg(feature = "contour")]
+            (RenderMode::Contour(a), RenderMode::Contour(b)) => a == b,
+            #[cfg(feature = "edges")]
+            (RenderMode::Edges(a), RenderMode::Edges(b)) => a == b,
+            #[cfg(feature = "emoji")]
+            (RenderMode::Emoji(a), RenderMode::Emoji(b)) => a == b,
+            #[cfg(feature = "glyph")]
+            (RenderMode::Glyph(a), RenderMode::Glyph(b)) => a == b,
+            #[cfg(feature = "halftone")]
+            (RenderMode::Halftone(a), RenderMode::Halftone(b)) => a == b,
+            (RenderMode::Custom(a), RenderMode::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Renderer for RenderMode {
+    fn name(&self) -> &str {
+        match self {
+            RenderMode::Ascii(_) => "ascii",
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(_) => "braille",
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { .. } => "background-string",
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(_) => "half-block",
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(options) => match options.kind {
+                BlockKind::Quadrant => "quadrant",
+                BlockKind::Sextant => "sextant",
+            },
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(_) => "contour",
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(_) => "edges",
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(_) => "emoji",
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(_) => "glyph",
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(_) => "halftone",
+            RenderMode::Custom(renderer) => renderer.name(),
+        }
+    }
+
+    fn description(&self) -> &str {
+        match self {
+            RenderMode::Ascii(_) => "Characters of ASCII string picked by lightness",
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(_) => "Braille patterns, 2x4 dots per character",
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { .. } => "Text of background string on light pixels",
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(_) => "Half blocks, two pixels per character",
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(options) => match options.kind {
+                BlockKind::Quadrant => "Quadrant blocks, 2x2 pixels per character",
+                BlockKind::Sextant => "Sextant blocks, 2x3 pixels per character",
+            },
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(_) => "Isolines of lightness drawn with box-drawing characters",
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(_) => {
+                "Edges drawn with | / - \\ _ by their direction, ASCII string elsewhere"
+            }
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(_) => "Emoji with the closest colors, two columns per pixel",
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(_) => "Characters with the most similar shape",
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(_) => "Dots of rotated print screen, growing with lightness",
+            RenderMode::Custom(renderer) => renderer.description(),
+        }
+    }
+
+    fn cell_columns(&self) -> u32 {
+        match self {
+            RenderMode::Ascii(options) => ascii_string_width(&options.ascii_string),
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { string, .. } => ascii_string_width(string),
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(options) => ascii_string_width(&options.ascii_string),
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(_) => WIDE_CELL_COLUMNS,
+            RenderMode::Custom(renderer) => renderer.cell_columns(),
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(_) => 1,
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(_) => 1,
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(_) => 1,
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(_) => 1,
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(_) => 1,
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(options) => ascii_string_width(&options.dots),
+        }
+    }
+
+    fn font_ratio(&self) -> f64 {
+        match self {
+            RenderMode::Ascii(_) => cell_font_ratio(DEFAULT_FONT_RATIO, self.cell_columns()),
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(_) => DEFAULT_BRAILLE_FONT_RATIO,
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { .. } => {
+                cell_font_ratio(DEFAULT_FONT_RATIO, self.cell_columns())
+            }
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(_) => DEFAULT_HALF_BLOCK_FONT_RATIO,
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(options) => options.kind.font_ratio(),
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(_) => DEFAULT_FONT_RATIO,
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(_) => cell_font_ratio(DEFAULT_FONT_RATIO, self.cell_columns()),
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(_) => EMOJI_FONT_RATIO,
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(options) => options.glyphs.font_ratio(),
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(options) => {
+                cell_font_ratio(options.font_ratio, self.cell_columns())
+            }
+            RenderMode::Custom(renderer) => renderer.font_ratio(),
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::prelude::*;
+    ///
+    /// assert!(RenderMode::default().validate().is_ok());
+    ///
+    /// let render_mode = RenderMode::Ascii(AsciiArtConverterOptions {
+    ///     ascii_string: String::new(),
+    ///     ..Default::default()
+    /// });
+    /// assert!(render_mode.validate().is_err());
+    /// ```
+    fn validate(&self) -> Result<(), AsciiArtConverterError> {
+        match self {
+            RenderMode::Ascii(options) => options.validate(),
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(_) => Ok(()),
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { string, .. } => {
+                Ramp::new(string)?;
+                Ok(())
+            }
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(_) => Ok(()),
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(_) => Ok(()),
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(_) => Ok(()),
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(options) => {
+                Ramp::new(&options.ascii_string)?;
+                Ok(())
+            }
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(_) => Ok(()),
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(options) => {
+                if options.glyphs.width == 0 || options.glyphs.height == 0 {
+                    return Err(SizeError.into());
+                }
+                if options.glyphs.glyphs.is_empty() {
+                    return Err(AsciiStringError.into());
+                }
+
+                Ok(())
+            }
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(options) => {
+                Ramp::new(&options.dots)?;
+                Ok(())
+            }
+            RenderMode::Custom(renderer) => renderer.validate(),
+        }
+    }
+
+    fn render(&self, img: &image::DynamicImage) -> Result<AsciiArt, AsciiArtConverterError> {
+        match self {
+            RenderMode::Ascii(options) => img.ascii_art(options),
+            #[cfg(feature = "braille")]
+            RenderMode::Braille(options) => Ok(img.braille_art(options)?),
+            #[cfg(feature = "background-string")]
+            RenderMode::BackgroundString { string, options } => {
+                img.background_string_art(string, options)
+            }
+            #[cfg(feature = "half-block")]
+            RenderMode::HalfBlock(options) => Ok(img.half_block_art(options)?),
+            #[cfg(feature = "blocks")]
+            RenderMode::Blocks(options) => Ok(img.block_art(options)?),
+            #[cfg(feature = "contour")]
+            RenderMode::Contour(options) => Ok(img.contour_art(options)?),
+            #[cfg(feature = "edges")]
+            RenderMode::Edges(options) => img.edge_art(options),
+            #[cfg(feature = "emoji")]
+            RenderMode::Emoji(options) => Ok(img.emoji_art(options)?),
+            #[cfg(feature = "glyph")]
+            RenderMode::Glyph(options) => img.glyph_art(options),
+            #[cfg(feature = "halftone")]
+            RenderMode::Halftone(options) => img.halftone_art(options),
+            RenderMode::Custom(renderer) => renderer.render(img),
+        }
+    }
+}
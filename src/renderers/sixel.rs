@@ -0,0 +1,392 @@
+//! Showing images using sixel graphics
+//!
+//! Supported by foot, mlterm, WezTerm, xterm (`xterm -ti vt340`) and some other terminals
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use tapciify::{
+//!     prelude::*,
+//!     renderers::sixel::{SixelConverter, SixelOptions},
+//! };
+//!
+//! # use image::imageops::FilterType;
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//!
+//! let result = img
+//!     .resize_custom_ratio(Some(320), None, 1.0, FilterType::Triangle)
+//!     .sixel(&SixelOptions::default());
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Default maximal amount of colors in palette
+pub const DEFAULT_SIXEL_COLORS: usize = 256;
+
+/// Pixels with lower alpha are left transparent
+pub const SIXEL_ALPHA_THRESHOLD: u8 = 128;
+
+/// Convert image into [`Sixel`]
+pub trait SixelConverter {
+    /// Quantize image and convert it into [`Sixel`], which can be printed to show image in terminal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::renderers::sixel::{SixelConverter, SixelOptions};
+    ///
+    /// let img = RgbaImage::from_fn(2, 2, |x, _| match x {
+    ///     0 => Rgba([255, 0, 0, 255]),
+    ///     _ => Rgba([0, 0, 255, 255]),
+    /// });
+    /// let result = img.sixel(&SixelOptions::default());
+    ///
+    /// assert_eq!(
+    ///     result.to_string(),
+    ///     "\x1bP0;1;0q\"1;1;2;2#0;2;0;0;100#1;2;100;0;0#0?B$#1B\x1b\\"
+    /// );
+    /// ```
+    ///
+    /// Repeated sixels are run-length encoded, transparent pixels are skipped:
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::renderers::sixel::{SixelConverter, SixelOptions};
+    ///
+    /// let img = RgbaImage::from_fn(8, 7, |x, _| match x {
+    ///     7 => Rgba([0, 0, 0, 0]),
+    ///     _ => Rgba([255, 255, 255, 255]),
+    /// });
+    /// let result = img.sixel(&SixelOptions::default());
+    ///
+    /// assert_eq!(
+    ///     result.to_string(),
+    ///     "\x1bP0;1;0q\"1;1;8;7#0;2;100;100;100#0!7~-#0!7@\x1b\\"
+    /// );
+    /// ```
+    fn sixel(&self, options: &SixelOptions) -> Sixel;
+}
+
+impl SixelConverter for image::DynamicImage {
+    fn sixel(&self, options: &SixelOptions) -> Sixel {
+        self.to_rgba8().sixel(options)
+    }
+}
+
+impl SixelConverter for image::RgbaImage {
+    fn sixel(&self, options: &SixelOptions) -> Sixel {
+        let mut histogram = HashMap::new();
+        for pixel in self
+            .pixels()
+            .filter(|pixel| pixel[3] >= SIXEL_ALPHA_THRESHOLD)
+        {
+            *histogram.entry([pixel[0], pixel[1], pixel[2]]).or_insert(0) += 1;
+        }
+
+        let palette = median_cut_histogram(histogram, options.colors);
+
+        let mut nearest_cache = HashMap::new();
+        let indices = self
+            .pixels()
+            .map(|pixel| {
+                if pixel[3] < SIXEL_ALPHA_THRESHOLD {
+                    return None;
+                }
+
+                let color = [pixel[0], pixel[1], pixel[2]];
+                let index = *nearest_cache
+                    .entry(color)
+                    .or_insert_with(|| nearest_color(&palette, color));
+
+                Some(index)
+            })
+            .collect();
+
+        Sixel {
+            palette,
+            indices,
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+}
+
+/// Options for [`SixelConverter::sixel`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SixelOptions {
+    /// Maximal amount of colors in palette (from 1 to 256)
+    pub colors: usize,
+}
+
+impl Default for SixelOptions {
+    fn default() -> SixelOptions {
+        SixelOptions {
+            colors: DEFAULT_SIXEL_COLORS,
+        }
+    }
+}
+
+/// Image quantized for sixel graphics
+///
+/// Use [`fmt::Display`] to get sixel stream
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Sixel {
+    /// Colors used by image
+    pub palette: Vec<[u8; 3]>,
+    /// Palette index of every pixel, [`None`] for transparent pixels
+    pub indices: Vec<Option<usize>>,
+    /// Width of the image in pixels
+    pub width: u32,
+    /// Height of the image in pixels
+    pub height: u32,
+}
+
+impl fmt::Display for Sixel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Transparent background, so unset pixels are left untouched
+        write!(f, "\x1bP0;1;0q\"1;1;{};{}", self.width, self.height)?;
+
+        for (i, [r, g, b]) in self.palette.iter().enumerate() {
+            write!(
+                f,
+                "#{};2;{};{};{}",
+                i,
+                to_percent(*r),
+                to_percent(*g),
+                to_percent(*b)
+            )?;
+        }
+
+        let width = self.width as usize;
+        let bands = self.height.div_ceil(6) as usize;
+
+        for band in 0..bands {
+            if band > 0 {
+                write!(f, "-")?;
+            }
+
+            let rows = band * 6..((band + 1) * 6).min(self.height as usize);
+
+            let mut colors = rows
+                .clone()
+                .flat_map(|y| &self.indices[y * width..(y + 1) * width])
+                .filter_map(|index| *index)
+                .collect::<Vec<usize>>();
+            colors.sort_unstable();
+            colors.dedup();
+
+            for (i, color) in colors.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "$")?;
+                }
+
+                let sixels = (0..width)
+                    .map(|x| {
+                        rows.clone()
+                            .enumerate()
+                            .filter(|(_, y)| self.indices[y * width + x] == Some(*color))
+                            .fold(0u8, |bits, (bit, _)| bits | 1 << bit)
+                    })
+                    .collect::<Vec<u8>>();
+
+                write!(f, "#{}{}", color, encode_sixels(&sixels))?;
+            }
+        }
+
+        write!(f, "\x1b\\")
+    }
+}
+
+/// Run-length encode sixels of one color, skipping trailing empty ones
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::sixel::encode_sixels;
+///
+/// assert_eq!(encode_sixels(&[1, 1, 1]), "@@@");
+/// assert_eq!(encode_sixels(&[1, 1, 1, 1, 63, 0, 0]), "!4@~");
+/// ```
+pub fn encode_sixels(sixels: &[u8]) -> String {
+    let end = sixels
+        .iter()
+        .rposition(|bits| *bits != 0)
+        .map_or(0, |i| i + 1);
+
+    let mut result = String::new();
+    let mut i = 0;
+
+    while i < end {
+        let bits = sixels[i];
+        let run = sixels[i..end].iter().take_while(|b| **b == bits).count();
+        let character = (0x3F + (bits & 0b111111)) as char;
+
+        if run > 3 {
+            result.push_str(&format!("!{}{}", run, character));
+        } else {
+            (0..run).for_each(|_| result.push(character));
+        }
+
+        i += run;
+    }
+
+    result
+}
+
+/// Convert color channel into sixel percents
+fn to_percent(channel: u8) -> u32 {
+    (channel as u32 * 100 + 127) / 255
+}
+
+/// Find the closest palette entry
+fn nearest_color(palette: &[[u8; 3]], color: [u8; 3]) -> usize {
+    palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, entry)| {
+            entry
+                .iter()
+                .zip(color.iter())
+                .map(|(a, b)| (*a as i32 - *b as i32).pow(2))
+                .sum::<i32>()
+        })
+        .map_or(0, |(i, _)| i)
+}
+
+/// Reduce colors to a palette using median cut
+///
+/// Colors are counted first, so boxes hold unique colors and their means are weighted by counts
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::renderers::sixel::median_cut;
+///
+/// let colors = vec![[0, 0, 0], [10, 0, 0], [200, 0, 0], [210, 0, 0]];
+///
+/// assert_eq!(median_cut(colors.clone(), 2), vec![[5, 0, 0], [205, 0, 0]]);
+/// assert_eq!(median_cut(colors, 1), vec![[105, 0, 0]]);
+///
+/// // Repeated colors pull the mean
+/// let colors = vec![[0, 0, 0], [0, 0, 0], [0, 0, 0], [100, 0, 0]];
+/// assert_eq!(median_cut(colors, 1), vec![[25, 0, 0]]);
+/// ```
+pub fn median_cut(colors: Vec<[u8; 3]>, max_colors: usize) -> Vec<[u8; 3]> {
+    let mut histogram = HashMap::new();
+    for color in colors {
+        *histogram.entry(color).or_insert(0) += 1;
+    }
+
+    median_cut_histogram(histogram, max_colors)
+}
+
+/// Same as [`median_cut`], but with colors already counted
+fn median_cut_histogram(histogram: HashMap<[u8; 3], u64>, max_colors: usize) -> Vec<[u8; 3]> {
+    if histogram.is_empty() {
+        return vec![];
+    }
+
+    // Sorted, so palette doesn't depend on order of hash map
+    let mut colors = histogram.into_iter().collect::<Vec<([u8; 3], u64)>>();
+    colors.sort_unstable();
+
+    let max_colors = max_colors.clamp(1, 256);
+    let mut boxes = vec![ColorBox::new(colors)];
+
+    while boxes.len() < max_colors {
+        // Split the box with the widest channel range
+        let Some((index, _)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, color_box)| color_box.range > 0)
+            .max_by_key(|(i, color_box)| (color_box.range, std::cmp::Reverse(*i)))
+        else {
+            break;
+        };
+
+        let (lower, upper) = boxes.remove(index).split();
+
+        boxes.insert(index, upper);
+        boxes.insert(index, lower);
+    }
+
+    boxes.iter().map(ColorBox::mean).collect()
+}
+
+/// Unique colors with their counts, and their widest channel
+struct ColorBox {
+    colors: Vec<([u8; 3], u64)>,
+    channel: usize,
+    range: u8,
+}
+
+impl ColorBox {
+    /// Create box, finding its widest channel
+    fn new(colors: Vec<([u8; 3], u64)>) -> ColorBox {
+        let (channel, range) = (0..3)
+            .map(|channel| (channel, channel_range(&colors, channel)))
+            .max_by_key(|(channel, range)| (*range, std::cmp::Reverse(*channel)))
+            .unwrap_or_default();
+
+        ColorBox {
+            colors,
+            channel,
+            range,
+        }
+    }
+
+    /// Split box at the median pixel of its widest channel, both halves are non-empty
+    fn split(mut self) -> (ColorBox, ColorBox) {
+        let channel = self.channel;
+        self.colors.sort_by_key(|(color, _)| color[channel]);
+
+        let total = self.colors.iter().map(|(_, count)| count).sum::<u64>();
+        let mut cumulative = 0;
+        let median = self
+            .colors
+            .iter()
+            .position(|(_, count)| {
+                cumulative += count;
+                cumulative * 2 >= total
+            })
+            .unwrap_or_default();
+
+        let upper = self
+            .colors
+            .split_off((median + 1).clamp(1, self.colors.len() - 1));
+
+        (ColorBox::new(self.colors), ColorBox::new(upper))
+    }
+
+    /// Mean of colors, weighted by counts
+    fn mean(&self) -> [u8; 3] {
+        let (sum, count) =
+            self.colors
+                .iter()
+                .fold(([0u64; 3], 0u64), |(sum, total), (color, count)| {
+                    (
+                        [0, 1, 2].map(|i| sum[i] + color[i] as u64 * count),
+                        total + count,
+                    )
+                });
+
+        sum.map(|channel| (channel / count.max(1)) as u8)
+    }
+}
+
+/// Difference between the biggest and smallest values of channel
+fn channel_range(colors: &[([u8; 3], u64)], channel: usize) -> u8 {
+    let values = colors.iter().map(|(color, _)| color[channel]);
+
+    values.clone().max().unwrap_or(0) - values.min().unwrap_or(0)
+}
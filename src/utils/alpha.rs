@@ -0,0 +1,223 @@
+//! Utils for showing transparent parts of images
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use image::imageops::FilterType;
+//! use tapciify::{
+//!     prelude::*,
+//!     utils::{alpha::AlphaMode, resize::DEFAULT_FONT_RATIO},
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//! let alpha_mode = AlphaMode::Transparent;
+//!
+//! let prepared_img = alpha_mode.apply_image(img.resize_custom_ratio(
+//!     Some(64),
+//!     None,
+//!     DEFAULT_FONT_RATIO,
+//!     FilterType::Triangle,
+//! ));
+//! let result = alpha_mode.apply_art(prepared_img.ascii_art(&AsciiArtConverterOptions {
+//!     color_mode: ColorMode::TrueColor,
+//!     ..Default::default()
+//! })?);
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error, fmt, str::FromStr};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{renderers::ascii::AsciiArt, utils::color::parse_hex_color};
+
+/// Alpha below which pixels are hidden by [`AlphaMode::Transparent`]
+pub const ALPHA_THRESHOLD: u8 = 128;
+
+/// Size of [`AlphaMode::Checkerboard`] squares in pixels of resized image
+pub const CHECKERBOARD_SIZE: u32 = 4;
+
+/// Colors of [`AlphaMode::Checkerboard`] squares
+const CHECKERBOARD_COLORS: [[u8; 3]; 2] = [[153, 153, 153], [102, 102, 102]];
+
+/// Way of showing transparent pixels
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlphaMode {
+    /// Transparent pixels are dark, like in lightness of [`crate::prelude::ToAsciiArtPixel`]
+    #[default]
+    Darken,
+    /// Blend pixels over color
+    Composite([u8; 3]),
+    /// Blend pixels over checkerboard, like in image editors
+    Checkerboard,
+    /// Leave cells of transparent pixels untouched, so terminal background is shown
+    Transparent,
+}
+
+impl AlphaMode {
+    /// Prepare resized image for rendering
+    ///
+    /// [`AlphaMode::Composite`] and [`AlphaMode::Checkerboard`] make image opaque,
+    /// [`AlphaMode::Transparent`] makes pixels above [`ALPHA_THRESHOLD`] opaque, so they keep
+    /// their lightness
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{DynamicImage, Rgba, RgbaImage};
+    /// use tapciify::utils::alpha::AlphaMode;
+    ///
+    /// let img = DynamicImage::from(RgbaImage::from_pixel(1, 1, Rgba([255, 255, 255, 51])));
+    ///
+    /// let result = AlphaMode::Composite([0, 0, 255]).apply_image(img.clone());
+    /// assert_eq!(result.to_rgba8().get_pixel(0, 0), &Rgba([51, 51, 255, 255]));
+    ///
+    /// let result = AlphaMode::Darken.apply_image(img.clone());
+    /// assert_eq!(result, img);
+    /// ```
+    pub fn apply_image(&self, img: image::DynamicImage) -> image::DynamicImage {
+        if *self == AlphaMode::Darken || !img.color().has_alpha() {
+            return img;
+        }
+
+        let mut img = img.to_rgba8();
+        let width = img.width();
+
+        #[cfg(feature = "rayon")]
+        let iter = img.par_pixels_mut();
+        #[cfg(not(feature = "rayon"))]
+        let iter = img.pixels_mut();
+
+        iter.enumerate().for_each(|(i, pixel)| {
+            let (x, y) = (i as u32 % width, i as u32 / width);
+
+            let background = match *self {
+                AlphaMode::Composite(color) => color,
+                AlphaMode::Checkerboard => {
+                    CHECKERBOARD_COLORS
+                        [((x / CHECKERBOARD_SIZE + y / CHECKERBOARD_SIZE) % 2) as usize]
+                }
+                AlphaMode::Darken | AlphaMode::Transparent => {
+                    if pixel[3] >= ALPHA_THRESHOLD {
+                        pixel[3] = 255;
+                    }
+                    return;
+                }
+            };
+
+            let alpha = pixel[3] as f32 / 255.0;
+            let [r, g, b] = [0, 1, 2].map(|i| {
+                (pixel[i] as f32 * alpha + background[i] as f32 * (1.0 - alpha)).round() as u8
+            });
+
+            pixel.0 = [r, g, b, 255];
+        });
+
+        img.into()
+    }
+
+    /// Clear cells of transparent pixels after rendering, when mode is
+    /// [`AlphaMode::Transparent`]
+    ///
+    /// Cleared cells are spaces of the same width, which aren't painted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{Rgba, RgbaImage};
+    /// use tapciify::{
+    ///     prelude::*,
+    ///     utils::{alpha::AlphaMode, color::ColorStyle},
+    /// };
+    ///
+    /// # fn main() -> Result<(), AsciiArtConverterError> {
+    /// colored::control::set_override(true);
+    ///
+    /// let img = RgbaImage::from_fn(2, 1, |x, _| Rgba([255, 0, 0, [255, 0][x as usize]]));
+    /// let result = AlphaMode::Transparent.apply_art(img.ascii_art(&AsciiArtConverterOptions {
+    ///     color_mode: ColorMode::Ansi256,
+    ///     color_style: ColorStyle::Background,
+    ///     ..Default::default()
+    /// })?);
+    ///
+    /// assert_eq!(result.to_string(), "\x1b[48;5;196m,\x1b[0m ");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply_art(&self, mut ascii_art: AsciiArt) -> AsciiArt {
+        if *self != AlphaMode::Transparent {
+            return ascii_art;
+        }
+
+        for pixel in ascii_art
+            .characters
+            .iter_mut()
+            .filter(|pixel| pixel.a < ALPHA_THRESHOLD)
+        {
+            let width = match &pixel.grapheme {
+                Some(grapheme) => grapheme.width(),
+                None => pixel.character.to_string().width(),
+            };
+
+            pixel.character = ' ';
+            pixel.grapheme = (width != 1).then(|| " ".repeat(width));
+            pixel.background = None;
+            pixel.a = 0;
+        }
+
+        ascii_art
+    }
+}
+
+impl FromStr for AlphaMode {
+    type Err = AlphaModeError;
+
+    /// Parse `darken`, `checkerboard`, `transparent` or `composite=<hex color>`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::alpha::AlphaMode;
+    ///
+    /// assert_eq!("transparent".parse(), Ok(AlphaMode::Transparent));
+    /// assert_eq!("composite=#ffffff".parse(), Ok(AlphaMode::Composite([255, 255, 255])));
+    /// assert!("composite=white".parse::<AlphaMode>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<AlphaMode, AlphaModeError> {
+        match s.split_once('=') {
+            Some(("composite", color)) => parse_hex_color(color)
+                .map(AlphaMode::Composite)
+                .ok_or(AlphaModeError),
+            None => match s {
+                "darken" => Ok(AlphaMode::Darken),
+                "checkerboard" => Ok(AlphaMode::Checkerboard),
+                "transparent" => Ok(AlphaMode::Transparent),
+                _ => Err(AlphaModeError),
+            },
+            _ => Err(AlphaModeError),
+        }
+    }
+}
+
+/// Error caused by invalid [`AlphaMode`] string
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AlphaModeError;
+
+impl error::Error for AlphaModeError {}
+
+impl fmt::Display for AlphaModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "alpha mode should be `darken`, `checkerboard`, `transparent` or `composite=<hex color>`"
+        )
+    }
+}
@@ -0,0 +1,413 @@
+//! Utils for painting text using different terminal color palettes
+
+use colored::{Color, Colorize};
+
+/// Standard xterm colors of the 16 ANSI colors palette
+const ANSI_16_COLORS: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0, 0, 0]),
+    (Color::Red, [205, 0, 0]),
+    (Color::Green, [0, 205, 0]),
+    (Color::Yellow, [205, 205, 0]),
+    (Color::Blue, [0, 0, 238]),
+    (Color::Magenta, [205, 0, 205]),
+    (Color::Cyan, [0, 205, 205]),
+    (Color::White, [229, 229, 229]),
+    (Color::BrightBlack, [127, 127, 127]),
+    (Color::BrightRed, [255, 0, 0]),
+    (Color::BrightGreen, [0, 255, 0]),
+    (Color::BrightYellow, [255, 255, 0]),
+    (Color::BrightBlue, [92, 92, 255]),
+    (Color::BrightMagenta, [255, 0, 255]),
+    (Color::BrightCyan, [0, 255, 255]),
+    (Color::BrightWhite, [255, 255, 255]),
+];
+
+/// Levels of the xterm 256 colors cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Color depth used for painting ASCII art
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum ColorMode {
+    /// Don't paint text
+    #[default]
+    None,
+    /// Shades of gray from the xterm 256 colors palette
+    Grayscale,
+    /// 16 ANSI colors, supported by almost every terminal, including Linux console
+    Ansi16,
+    /// xterm 256 colors palette: 6x6x6 cube and gray ramp
+    Ansi256,
+    /// 24-bit colors
+    TrueColor,
+}
+
+impl ColorMode {
+    /// Checks if text is painted at all
+    pub fn is_colored(&self) -> bool {
+        *self != ColorMode::None
+    }
+
+    /// Paint text with optional foreground and background colors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::color::ColorMode;
+    ///
+    /// colored::control::set_override(true);
+    ///
+    /// assert_eq!(ColorMode::None.paint("@", Some([255, 0, 0]), None), "@");
+    /// assert_eq!(
+    ///     ColorMode::Ansi256.paint("@", Some([255, 0, 0]), Some([0, 0, 0])),
+    ///     "\x1b[38;5;196;48;5;16m@\x1b[0m"
+    /// );
+    /// assert_eq!(ColorMode::Ansi256.paint(" ", None, Some([255, 0, 0])), "\x1b[48;5;196m \x1b[0m");
+    /// assert_eq!(ColorMode::Grayscale.paint("@", Some([255, 255, 255]), None), "\x1b[38;5;231m@\x1b[0m");
+    /// assert_eq!(ColorMode::Ansi16.paint("@", Some([250, 10, 10]), None), "\x1b[91m@\x1b[0m");
+    /// ```
+    pub fn paint(
+        &self,
+        text: &str,
+        foreground: Option<[u8; 3]>,
+        background: Option<[u8; 3]>,
+    ) -> String {
+        match self {
+            ColorMode::None => text.to_owned(),
+            ColorMode::TrueColor => {
+                let mut colored_string = text.normal();
+
+                if let Some([r, g, b]) = foreground {
+                    colored_string = colored_string.truecolor(r, g, b);
+                }
+                if let Some([r, g, b]) = background {
+                    colored_string = colored_string.on_truecolor(r, g, b);
+                }
+
+                colored_string.to_string()
+            }
+            ColorMode::Ansi16 => {
+                let mut colored_string = text.normal();
+
+                if let Some([r, g, b]) = foreground {
+                    colored_string = colored_string.color(rgb_to_ansi16(r, g, b));
+                }
+                if let Some([r, g, b]) = background {
+                    colored_string = colored_string.on_color(rgb_to_ansi16(r, g, b));
+                }
+
+                colored_string.to_string()
+            }
+            ColorMode::Ansi256 => paint_256(
+                text,
+                foreground.map(|[r, g, b]| rgb_to_ansi256(r, g, b)),
+                background.map(|[r, g, b]| rgb_to_ansi256(r, g, b)),
+            ),
+            ColorMode::Grayscale => paint_256(
+                text,
+                foreground.map(|[r, g, b]| rgb_to_grayscale256(r, g, b)),
+                background.map(|[r, g, b]| rgb_to_grayscale256(r, g, b)),
+            ),
+        }
+    }
+}
+
+impl From<bool> for ColorMode {
+    fn from(colored: bool) -> ColorMode {
+        match colored {
+            true => ColorMode::TrueColor,
+            false => ColorMode::None,
+        }
+    }
+}
+
+/// Way of applying pixel color to character cell
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum ColorStyle {
+    /// Paint character with pixel color
+    #[default]
+    Foreground,
+    /// Paint background with pixel color, character is black or white, whichever is more visible
+    Contrast,
+    /// Paint background with pixel color, character with darkened pixel color
+    Darkened,
+    /// Paint only background with pixel color, making solid color mosaic
+    Background,
+}
+
+impl ColorStyle {
+    /// Get foreground and background colors of character cell from pixel color
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::color::ColorStyle;
+    ///
+    /// let color = [250, 200, 0];
+    ///
+    /// assert_eq!(ColorStyle::Foreground.colors(color), (Some(color), None));
+    /// assert_eq!(ColorStyle::Contrast.colors(color), (Some([0, 0, 0]), Some(color)));
+    /// assert_eq!(ColorStyle::Darkened.colors(color), (Some([125, 100, 0]), Some(color)));
+    /// assert_eq!(ColorStyle::Background.colors(color), (None, Some(color)));
+    /// ```
+    pub fn colors(&self, color: [u8; 3]) -> (Option<[u8; 3]>, Option<[u8; 3]>) {
+        match self {
+            ColorStyle::Foreground => (Some(color), None),
+            ColorStyle::Contrast => {
+                let luma = image::Pixel::to_luma(&image::Rgb(color))[0];
+                let contrast = if luma > 127 {
+                    [0, 0, 0]
+                } else {
+                    [255, 255, 255]
+                };
+
+                (Some(contrast), Some(color))
+            }
+            ColorStyle::Darkened => (Some(color.map(|channel| channel / 2)), Some(color)),
+            ColorStyle::Background => (None, Some(color)),
+        }
+    }
+}
+
+/// Way of picking single color for character cell covering multiple pixels
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum CellColor {
+    /// Color of the top left pixel
+    TopLeft,
+    /// Mean color of all pixels
+    Mean,
+    /// Mean color of lit pixels, or all pixels if none is lit
+    #[default]
+    MeanLit,
+    /// Median of every channel of all pixels
+    Median,
+}
+
+impl CellColor {
+    /// Pick RGBA color of cell from pixels and whether they are lit, top left pixel goes first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::color::CellColor;
+    ///
+    /// let pixels = [
+    ///     ([0, 0, 0, 255], false),
+    ///     ([200, 100, 0, 255], true),
+    ///     ([100, 50, 0, 255], true),
+    ///     ([255, 255, 255, 255], false),
+    /// ];
+    ///
+    /// assert_eq!(CellColor::TopLeft.pick(&pixels), [0, 0, 0, 255]);
+    /// assert_eq!(CellColor::Mean.pick(&pixels), [139, 101, 64, 255]);
+    /// assert_eq!(CellColor::MeanLit.pick(&pixels), [150, 75, 0, 255]);
+    /// assert_eq!(CellColor::Median.pick(&pixels), [200, 100, 0, 255]);
+    /// ```
+    pub fn pick(&self, pixels: &[([u8; 4], bool)]) -> [u8; 4] {
+        match self {
+            CellColor::TopLeft => pixels.first().map_or([0; 4], |(color, _)| *color),
+            CellColor::Mean => mean(pixels.iter().map(|(color, _)| *color)),
+            CellColor::MeanLit => match pixels.iter().any(|(_, lit)| *lit) {
+                true => mean(
+                    pixels
+                        .iter()
+                        .filter(|(_, lit)| *lit)
+                        .map(|(color, _)| *color),
+                ),
+                false => CellColor::Mean.pick(pixels),
+            },
+            CellColor::Median => std::array::from_fn(|channel| {
+                let mut values = pixels
+                    .iter()
+                    .map(|(color, _)| color[channel])
+                    .collect::<Vec<u8>>();
+                values.sort_unstable();
+
+                values.get(values.len() / 2).copied().unwrap_or(0)
+            }),
+        }
+    }
+}
+
+/// Mean of RGBA colors, rounded
+fn mean(colors: impl Iterator<Item = [u8; 4]>) -> [u8; 4] {
+    let (sums, count) = colors.fold(([0u32; 4], 0u32), |(mut sums, count), color| {
+        sums.iter_mut()
+            .zip(color)
+            .for_each(|(sum, channel)| *sum += channel as u32);
+        (sums, count + 1)
+    });
+
+    sums.map(|sum| (sum + count / 2).checked_div(count).unwrap_or(0) as u8)
+}
+
+/// Paint text using xterm 256 colors palette indices, `colored` doesn't support them
+fn paint_256(text: &str, foreground: Option<u8>, background: Option<u8>) -> String {
+    if !colored::control::SHOULD_COLORIZE.should_colorize() {
+        return text.to_owned();
+    }
+
+    let codes = [
+        foreground.map(|foreground| format!("38;5;{}", foreground)),
+        background.map(|background| format!("48;5;{}", background)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>();
+
+    if codes.is_empty() {
+        return text.to_owned();
+    }
+
+    format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+}
+
+/// Squared euclidean distance between colors
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (*a as i32 - *b as i32).pow(2) as u32)
+        .sum()
+}
+
+/// Find the closest color of the 16 ANSI colors
+///
+/// # Examples
+///
+/// ```
+/// use colored::Color;
+/// use tapciify::utils::color::rgb_to_ansi16;
+///
+/// assert_eq!(rgb_to_ansi16(0, 0, 0), Color::Black);
+/// assert_eq!(rgb_to_ansi16(0, 200, 210), Color::Cyan);
+/// assert_eq!(rgb_to_ansi16(250, 250, 250), Color::BrightWhite);
+/// ```
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI_16_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb, [r, g, b]))
+        .map_or(Color::White, |(color, _)| *color)
+}
+
+/// Find the closest color of the xterm 256 colors cube and gray ramp
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::color::rgb_to_ansi256;
+///
+/// assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+/// assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+/// assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+/// assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+/// ```
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |channel: u8| match channel {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (channel - 35) / 40,
+    };
+
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube_color = [
+        CUBE_LEVELS[ri as usize],
+        CUBE_LEVELS[gi as usize],
+        CUBE_LEVELS[bi as usize],
+    ];
+
+    let gray_index = gray_ramp_index(((r as u16 + g as u16 + b as u16) / 3) as u8);
+    let gray = 8 + 10 * gray_index;
+
+    match distance([gray, gray, gray], [r, g, b]) < distance(cube_color, [r, g, b]) {
+        true => 232 + gray_index,
+        false => 16 + 36 * ri + 6 * gi + bi,
+    }
+}
+
+/// Convert color into shade of gray from the xterm 256 colors palette
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::color::rgb_to_grayscale256;
+///
+/// assert_eq!(rgb_to_grayscale256(0, 0, 0), 16);
+/// assert_eq!(rgb_to_grayscale256(128, 128, 128), 244);
+/// assert_eq!(rgb_to_grayscale256(255, 255, 255), 231);
+/// ```
+pub fn rgb_to_grayscale256(r: u8, g: u8, b: u8) -> u8 {
+    let luma = image::Pixel::to_luma(&image::Rgb([r, g, b]))[0];
+
+    match luma {
+        0..4 => 16,
+        247.. => 231,
+        _ => 232 + gray_ramp_index(luma),
+    }
+}
+
+/// Index of the closest shade in 24 grays ramp (8, 18, ..., 238)
+fn gray_ramp_index(lightness: u8) -> u8 {
+    ((lightness.saturating_sub(3)) / 10).min(23)
+}
+
+/// Convert sRGB color into CIELAB (D65), where euclidean distance follows perceived difference
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::color::rgb_to_lab;
+///
+/// let [l, a, b] = rgb_to_lab([255, 255, 255]);
+/// assert!((l - 100.0).abs() < 0.01 && a.abs() < 0.01 && b.abs() < 0.01);
+///
+/// let [l, a, b] = rgb_to_lab([255, 0, 0]);
+/// assert!((l - 53.24).abs() < 0.01 && (a - 80.09).abs() < 0.01 && (b - 67.2).abs() < 0.01);
+/// ```
+pub fn rgb_to_lab(rgb: [u8; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(|channel| srgb_to_linear(channel as f32 / 255.0));
+
+    // Relative to D65 white point
+    let xyz = [
+        (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047,
+        0.2126729 * r + 0.7151522 * g + 0.072175 * b,
+        (0.0193339 * r + 0.119192 * g + 0.9503041 * b) / 1.08883,
+    ];
+    let [x, y, z] = xyz.map(|t| match t > 216.0 / 24389.0 {
+        true => t.cbrt(),
+        false => (24389.0 / 27.0 * t + 16.0) / 116.0,
+    });
+
+    [116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z)]
+}
+
+/// Parse hex color, like `#dd2e44` or `dd2e44`
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::color::parse_hex_color;
+///
+/// assert_eq!(parse_hex_color("#dd2e44"), Some([221, 46, 68]));
+/// assert_eq!(parse_hex_color("f6d743"), Some([246, 215, 67]));
+/// assert_eq!(parse_hex_color("red"), None);
+/// ```
+pub fn parse_hex_color(color: &str) -> Option<[u8; 3]> {
+    let color = color.trim().trim_start_matches('#');
+    if color.len() != 6 || !color.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(&color[i..i + 2], 16).ok();
+
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// Decode sRGB gamma of channel (from 0.0 to 1.0)
+pub(crate) fn srgb_to_linear(channel: f32) -> f32 {
+    match channel <= 0.04045 {
+        true => channel / 12.92,
+        false => ((channel + 0.055) / 1.055).powf(2.4),
+    }
+}
@@ -0,0 +1,341 @@
+//! Utils for dithering lightness of pixels, so gradients don't band
+//!
+//! Used for both picking characters from ASCII string and binary decisions of
+//! [`crate::renderers::braille`] and [`crate::renderers::background_string`]
+
+use std::sync::OnceLock;
+
+/// Size of generated blue noise texture
+pub const BLUE_NOISE_SIZE: usize = 32;
+
+/// Dithering algorithm
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum Dithering {
+    /// No dithering
+    #[default]
+    None,
+    /// Floyd–Steinberg error diffusion
+    FloydSteinberg,
+    /// Atkinson error diffusion, diffuses only 3/4 of error, so output is more contrast
+    Atkinson,
+    /// Sierra (three rows) error diffusion
+    Sierra,
+    /// Ordered dithering using 2x2 Bayer matrix
+    Bayer2,
+    /// Ordered dithering using 4x4 Bayer matrix
+    Bayer4,
+    /// Ordered dithering using 8x8 Bayer matrix
+    Bayer8,
+    /// Ordered dithering using blue noise texture
+    BlueNoise,
+}
+
+/// Error diffusion kernel: x and y offsets with weights, and divisor
+type Kernel = (&'static [(i32, i32, f32)], f32);
+
+const FLOYD_STEINBERG: Kernel = (&[(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)], 16.0);
+
+const ATKINSON: Kernel = (
+    &[
+        (1, 0, 1.0),
+        (2, 0, 1.0),
+        (-1, 1, 1.0),
+        (0, 1, 1.0),
+        (1, 1, 1.0),
+        (0, 2, 1.0),
+    ],
+    8.0,
+);
+
+const SIERRA: Kernel = (
+    &[
+        (1, 0, 5.0),
+        (2, 0, 3.0),
+        (-2, 1, 2.0),
+        (-1, 1, 4.0),
+        (0, 1, 5.0),
+        (1, 1, 4.0),
+        (2, 1, 2.0),
+        (-1, 2, 2.0),
+        (0, 2, 3.0),
+        (1, 2, 2.0),
+    ],
+    32.0,
+);
+
+impl Dithering {
+    /// Quantize lightness values (from 0.0 to 1.0) into level indices (from 0 to `levels - 1`)
+    ///
+    /// [`Dithering::None`] matches [`crate::renderers::ascii::ascii_character`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::dither::Dithering;
+    ///
+    /// let gray = vec![0.5; 4];
+    ///
+    /// assert_eq!(Dithering::None.quantize(&gray, 2, 2, 2), vec![0, 0, 0, 0]);
+    /// assert_eq!(Dithering::FloydSteinberg.quantize(&gray, 2, 2, 2), vec![1, 0, 0, 1]);
+    /// assert_eq!(Dithering::Bayer2.quantize(&gray, 2, 2, 2), vec![0, 1, 1, 0]);
+    /// ```
+    pub fn quantize(
+        &self,
+        lightness: &[f32],
+        width: u32,
+        height: u32,
+        levels: usize,
+    ) -> Vec<usize> {
+        let max_level = levels.saturating_sub(1);
+        let steps = max_level as f32;
+
+        let quantize_level =
+            |value: f32| (value.clamp(0.0, 1.0) * steps).round().clamp(0.0, steps) as usize;
+
+        match self.kernel() {
+            Some(kernel) => error_diffusion(lightness, width, height, kernel, |value| {
+                let level = quantize_level(value);
+                (level, level as f32 / steps.max(1.0))
+            }),
+            None => lightness
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let offset = self.ordered_threshold(i as u32 % width, i as u32 / width);
+                    ((value * steps + offset) as usize).min(max_level)
+                })
+                .collect(),
+        }
+    }
+
+    /// Check if lightness values (from 0.0 to 1.0) are higher than thresholds
+    ///
+    /// [`Dithering::None`] matches [`crate::utils::threshold::ThresholdPixel::threshold_pixel`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::dither::Dithering;
+    ///
+    /// let gray = vec![0.5; 4];
+    /// let thresholds = vec![0.5; 4];
+    ///
+    /// assert_eq!(Dithering::None.threshold(&gray, &thresholds, 2, 2), vec![false; 4]);
+    /// assert_eq!(
+    ///     Dithering::FloydSteinberg.threshold(&gray, &thresholds, 2, 2),
+    ///     vec![false, true, true, false]
+    /// );
+    /// assert_eq!(
+    ///     Dithering::Bayer2.threshold(&gray, &thresholds, 2, 2),
+    ///     vec![true, false, false, true]
+    /// );
+    ///
+    /// // Blue noise texture has every threshold once
+    /// let gray = vec![0.5; 32 * 32];
+    /// let thresholds = vec![0.5; 32 * 32];
+    /// let lit = Dithering::BlueNoise.threshold(&gray, &thresholds, 32, 32);
+    ///
+    /// assert_eq!(lit.into_iter().filter(|lit| *lit).count(), 32 * 32 / 2);
+    /// ```
+    pub fn threshold(
+        &self,
+        lightness: &[f32],
+        thresholds: &[f32],
+        width: u32,
+        height: u32,
+    ) -> Vec<bool> {
+        match self.kernel() {
+            Some(kernel) => {
+                let mut index = 0;
+
+                error_diffusion(lightness, width, height, kernel, |value| {
+                    let lit = value > thresholds[index];
+                    index += 1;
+                    (lit, lit as u8 as f32)
+                })
+            }
+            None => lightness
+                .iter()
+                .zip(thresholds)
+                .enumerate()
+                .map(|(i, (value, threshold))| {
+                    let offset = match self {
+                        Dithering::None => 0.0,
+                        _ => self.ordered_threshold(i as u32 % width, i as u32 / width) - 0.5,
+                    };
+
+                    *value > threshold + offset
+                })
+                .collect(),
+        }
+    }
+
+    /// Error diffusion kernel of algorithm
+    fn kernel(&self) -> Option<Kernel> {
+        match self {
+            Dithering::FloydSteinberg => Some(FLOYD_STEINBERG),
+            Dithering::Atkinson => Some(ATKINSON),
+            Dithering::Sierra => Some(SIERRA),
+            _ => None,
+        }
+    }
+
+    /// Threshold of ordered dithering (from 0.0 to 1.0), 0.0 for no dithering
+    fn ordered_threshold(&self, x: u32, y: u32) -> f32 {
+        match self {
+            Dithering::Bayer2 => bayer_threshold(1, x, y),
+            Dithering::Bayer4 => bayer_threshold(2, x, y),
+            Dithering::Bayer8 => bayer_threshold(3, x, y),
+            Dithering::BlueNoise => {
+                let size = BLUE_NOISE_SIZE as u32;
+                blue_noise()[((y % size) * size + x % size) as usize]
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Diffuse quantization error to neighbouring pixels in the scan order
+fn error_diffusion<T>(
+    lightness: &[f32],
+    width: u32,
+    height: u32,
+    (weights, divisor): Kernel,
+    mut quantize: impl FnMut(f32) -> (T, f32),
+) -> Vec<T> {
+    let (width, height) = (width as i32, height as i32);
+    let mut values = lightness.to_vec();
+
+    (0..values.len())
+        .map(|i| {
+            let (result, quantized) = quantize(values[i]);
+            let error = values[i] - quantized;
+
+            let (x, y) = (i as i32 % width, i as i32 / width);
+
+            for (dx, dy, weight) in weights {
+                let (nx, ny) = (x + dx, y + dy);
+
+                if nx >= 0 && nx < width && ny < height {
+                    values[(ny * width + nx) as usize] += error * weight / divisor;
+                }
+            }
+
+            result
+        })
+        .collect()
+}
+
+/// Threshold from Bayer matrix of size `2^order`
+fn bayer_threshold(order: u32, x: u32, y: u32) -> f32 {
+    let size = 1 << order;
+
+    // Interleave bits of x ^ y and y in reverse order
+    let value = (0..order).fold(0, |value, bit| {
+        let x_bit = (x >> bit) & 1;
+        let y_bit = (y >> bit) & 1;
+
+        value | ((x_bit ^ y_bit) << (2 * (order - bit) - 1)) | (y_bit << (2 * (order - bit) - 2))
+    });
+
+    (value as f32 + 0.5) / (size * size) as f32
+}
+
+/// Blue noise texture, generated once using void-and-cluster method
+fn blue_noise() -> &'static [f32] {
+    static BLUE_NOISE: OnceLock<Vec<f32>> = OnceLock::new();
+
+    BLUE_NOISE.get_or_init(|| {
+        let size = BLUE_NOISE_SIZE;
+        let total = size * size;
+
+        // Toroidal gaussian filter, used for finding clusters and voids
+        let sigma = 1.5f32;
+        let filter = (0..total)
+            .map(|i| {
+                let dx = (i % size).min(size - i % size) as f32;
+                let dy = (i / size).min(size - i / size) as f32;
+                (-(dx * dx + dy * dy) / (2.0 * sigma * sigma)).exp()
+            })
+            .collect::<Vec<f32>>();
+
+        let mut pattern = vec![false; total];
+        let mut energy = vec![0.0f32; total];
+
+        let toggle = |pattern: &mut Vec<bool>, energy: &mut Vec<f32>, index: usize| {
+            pattern[index] = !pattern[index];
+            let sign = if pattern[index] { 1.0 } else { -1.0 };
+            let (x, y) = (index % size, index / size);
+
+            for (i, value) in energy.iter_mut().enumerate() {
+                let dx = (i % size + size - x) % size;
+                let dy = (i / size + size - y) % size;
+                *value += sign * filter[dy * size + dx];
+            }
+        };
+
+        let tightest_cluster = |pattern: &[bool], energy: &[f32]| {
+            (0..total)
+                .filter(|i| pattern[*i])
+                .max_by(|a, b| energy[*a].total_cmp(&energy[*b]))
+                .unwrap_or(0)
+        };
+        let largest_void = |pattern: &[bool], energy: &[f32]| {
+            (0..total)
+                .filter(|i| !pattern[*i])
+                .min_by(|a, b| energy[*a].total_cmp(&energy[*b]))
+                .unwrap_or(0)
+        };
+
+        // Initial pattern from deterministic pseudo random numbers
+        let mut seed = 0x2545F491u32;
+        let initial_count = total / 10;
+
+        while pattern.iter().filter(|lit| **lit).count() < initial_count {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+
+            let index = seed as usize % total;
+            if !pattern[index] {
+                toggle(&mut pattern, &mut energy, index);
+            }
+        }
+
+        // Move points from clusters to voids, until pattern is evenly distributed
+        loop {
+            let cluster = tightest_cluster(&pattern, &energy);
+            toggle(&mut pattern, &mut energy, cluster);
+
+            let void = largest_void(&pattern, &energy);
+            toggle(&mut pattern, &mut energy, void);
+
+            if void == cluster {
+                break;
+            }
+        }
+
+        let mut ranks = vec![0usize; total];
+
+        // Remove points from clusters of the initial pattern
+        let (mut initial_pattern, mut initial_energy) = (pattern.clone(), energy.clone());
+        for rank in (0..initial_count).rev() {
+            let cluster = tightest_cluster(&initial_pattern, &initial_energy);
+            toggle(&mut initial_pattern, &mut initial_energy, cluster);
+            ranks[cluster] = rank;
+        }
+
+        // Fill voids of the initial pattern
+        for rank in initial_count..total {
+            let void = largest_void(&pattern, &energy);
+            toggle(&mut pattern, &mut energy, void);
+            ranks[void] = rank;
+        }
+
+        ranks
+            .into_iter()
+            .map(|rank| (rank as f32 + 0.5) / total as f32)
+            .collect()
+    })
+}
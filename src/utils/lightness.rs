@@ -0,0 +1,71 @@
+//! Models of perceived lightness, used to pick characters and lit pixels
+
+use image::Pixel;
+
+use crate::utils::color::{rgb_to_lab, srgb_to_linear};
+
+/// Way of calculating lightness of colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum LightnessModel {
+    /// Rec. 601 (SDTV) weights on gamma-encoded values
+    Rec601,
+    /// Rec. 709 (HDTV) weights on gamma-encoded values, same as luma of image crate
+    #[default]
+    Rec709,
+    /// Rec. 2020 (UHDTV) weights on gamma-encoded values
+    Rec2020,
+    /// Relative luminance of linear light, dark midtones
+    Linear,
+    /// CIELAB L*, perceptually uniform steps of lightness
+    Lab,
+    /// HSP color model, brighter blues and reds
+    Hsp,
+}
+
+impl LightnessModel {
+    /// Lightness of sRGB color (from 0.0 to 1.0)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::lightness::LightnessModel;
+    ///
+    /// let blue = [0, 0, 255];
+    ///
+    /// assert!(LightnessModel::Rec709.lightness(blue) < 0.1);
+    /// assert!(LightnessModel::Hsp.lightness(blue) > 0.3);
+    ///
+    /// // Midtones are darker in linear light, and about half lightness in CIELAB
+    /// let gray = [128, 128, 128];
+    ///
+    /// assert!(LightnessModel::Linear.lightness(gray) < 0.25);
+    /// assert!((LightnessModel::Lab.lightness(gray) - 0.54).abs() < 0.01);
+    /// ```
+    pub fn lightness(&self, rgb: [u8; 3]) -> f32 {
+        let lightness = match self {
+            LightnessModel::Rec601 => weighted(rgb, [0.299, 0.587, 0.114]),
+            LightnessModel::Rec709 => image::Rgb(rgb).to_luma()[0] as f32 / 255.0,
+            LightnessModel::Rec2020 => weighted(rgb, [0.2627, 0.678, 0.0593]),
+            LightnessModel::Linear => {
+                let [r, g, b] = rgb.map(|channel| srgb_to_linear(channel as f32 / 255.0));
+                0.2126 * r + 0.7152 * g + 0.0722 * b
+            }
+            LightnessModel::Lab => rgb_to_lab(rgb)[0] / 100.0,
+            LightnessModel::Hsp => {
+                let [r, g, b] = rgb.map(|channel| (channel as f32 / 255.0).powi(2));
+                (0.299 * r + 0.587 * g + 0.114 * b).sqrt()
+            }
+        };
+
+        lightness.clamp(0.0, 1.0)
+    }
+}
+
+/// Weighted sum of gamma-encoded channels
+fn weighted(rgb: [u8; 3], weights: [f32; 3]) -> f32 {
+    rgb.into_iter()
+        .zip(weights)
+        .map(|(channel, weight)| channel as f32 / 255.0 * weight)
+        .sum()
+}
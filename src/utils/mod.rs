@@ -1,4 +1,12 @@
+pub mod alpha;
+pub mod color;
+pub mod dither;
+pub mod lightness;
+pub mod preprocess;
 pub mod resize;
+pub mod tone;
+pub mod transform;
+pub mod trim;
 
 #[cfg(feature = "player")]
 pub mod player;
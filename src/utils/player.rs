@@ -30,6 +30,7 @@ use std::{error, fmt};
 
 use crossterm::cursor::MoveUp;
 use crossterm::execute;
+use crossterm::terminal::window_size;
 use image::imageops::FilterType;
 use imageproc::contrast::adaptive_threshold;
 #[cfg(feature = "rayon")]
@@ -43,9 +44,30 @@ use rayon::prelude::*;
 use crate::prelude::*;
 
 use crate::renderers::ascii::{AsciiStringError, DEFAULT_ASCII_STRING, SizeError};
-use crate::renderers::background_string::BackgroundStringArtConverter;
-use crate::renderers::braille::BrailleArtConverter;
-use crate::utils::resize::DEFAULT_FONT_RATIO;
+use crate::renderers::background_string::BackgroundStringArtConverterOptions;
+use crate::renderers::blocks::{BlockArtConverterOptions, BlockKind};
+use crate::renderers::braille::BrailleArtConverterOptions;
+use crate::renderers::half_block::HalfBlockArtConverterOptions;
+use crate::renderers::iterm::{ItermImageConverter, ItermImageOptions};
+use crate::renderers::kitty::{KittyGraphicsConverter, KittyGraphicsOptions};
+use crate::renderers::renderer::{RenderMode, Renderer};
+use crate::renderers::sixel::{SixelConverter, SixelOptions};
+use crate::utils::alpha::AlphaMode;
+use crate::utils::color::{CellColor, ColorStyle};
+use crate::utils::dither::Dithering;
+use crate::utils::lightness::LightnessModel;
+use crate::utils::preprocess::Preprocessing;
+use crate::utils::resize::{DEFAULT_FONT_RATIO, calc_new_size};
+use crate::utils::threshold::ThresholdMode;
+use crate::utils::tone::{ToneMap, ToneMapping, ToneScope};
+use crate::utils::transform::{Transform, open_oriented};
+use crate::utils::trim::{Bounds, TrimScope, content_bounds, trim_borders};
+
+/// Width of terminal cell in pixels, used when terminal doesn't report it
+pub const DEFAULT_CELL_WIDTH: u32 = 10;
+
+/// Width of terminal in cells, used when terminal doesn't report it
+pub const DEFAULT_TERMINAL_COLUMNS: u32 = 80;
 
 /// Calculate frame time in millis (1 / framerate)
 ///
@@ -73,13 +95,45 @@ impl AsciiPlayer {
         ascii_string.chars().rev().collect()
     }
 
-    /// Renders frame using [`AsciiPlayerOptions`]
-    pub fn render_frame(
+    /// Opens image in its EXIF orientation, and applies transform from [`AsciiPlayerOptions`]
+    fn open_transformed_frame(
         path: &PathBuf,
         options: &AsciiPlayerOptions,
-        converter_options: &AsciiArtConverterOptions,
-    ) -> Result<AsciiArt, AsciiPlayerError> {
-        let img = image::open(path)?;
+    ) -> Result<image::DynamicImage, AsciiPlayerError> {
+        Ok(options.transform.apply(open_oriented(path)?))
+    }
+
+    /// Opens transformed image, and applies trimming and preprocessing from
+    /// [`AsciiPlayerOptions`]
+    fn decode_frame(
+        path: &PathBuf,
+        options: &AsciiPlayerOptions,
+        sequence: &SequenceContext,
+    ) -> Result<image::DynamicImage, AsciiPlayerError> {
+        let img = AsciiPlayer::open_transformed_frame(path, options)?;
+        let img = match (sequence.bounds, options.trim) {
+            (Some(bounds), _) => bounds.crop(img),
+            (None, Some(tolerance)) => trim_borders(img, tolerance),
+            (None, None) => img,
+        };
+
+        Ok(options.preprocessing.apply(img))
+    }
+
+    /// Opens image and applies preprocessing, tone mapping and threshold from
+    /// [`AsciiPlayerOptions`]
+    ///
+    /// Borders and tone map of whole sequence are used instead of ones of frame, when given
+    fn open_frame(
+        path: &PathBuf,
+        options: &AsciiPlayerOptions,
+        sequence: &SequenceContext,
+    ) -> Result<image::DynamicImage, AsciiPlayerError> {
+        let img = AsciiPlayer::decode_frame(path, options, sequence)?;
+        let img = match &sequence.tone_map {
+            Some(tone_map) => tone_map.apply(img),
+            None => options.tone_mapping.apply(img),
+        };
         let processed_img = match options.threshold {
             Some(threshold) => {
                 image::DynamicImage::from(adaptive_threshold(&img.to_luma8(), threshold))
@@ -87,22 +141,161 @@ impl AsciiPlayer {
             None => img,
         };
 
-        let prepared_img = processed_img.resize_custom_ratio(
-            options.width,
+        Ok(processed_img)
+    }
+
+    /// Renders frame using [`AsciiPlayerOptions`]
+    pub fn render_frame(
+        path: &PathBuf,
+        options: &AsciiPlayerOptions,
+        converter_options: &AsciiArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiPlayerError> {
+        let processed_img = AsciiPlayer::open_frame(path, options, &SequenceContext::default())?;
+
+        AsciiPlayer::convert_frame(&processed_img, options, converter_options)
+    }
+
+    /// Converts opened frame into [`AsciiArt`]
+    fn convert_frame(
+        processed_img: &image::DynamicImage,
+        options: &AsciiPlayerOptions,
+        converter_options: &AsciiArtConverterOptions,
+    ) -> Result<AsciiArt, AsciiPlayerError> {
+        let prepared_img = options
+            .alpha_mode
+            .apply_image(processed_img.resize_custom_ratio(
+                options.width,
+                options.height,
+                options.font_ratio,
+                options.filter,
+            ));
+
+        let render_mode = options
+            .render_mode
+            .clone()
+            .unwrap_or_else(|| AsciiPlayer::flags_render_mode(options, converter_options));
+        let ascii_art = options
+            .alpha_mode
+            .apply_art(render_mode.render(&prepared_img)?);
+
+        Ok(ascii_art.to_color_style(options.color_style))
+    }
+
+    /// Picks renderer using [`AsciiPlayerOptions::braille`], [`AsciiPlayerOptions::half_block`],
+    /// [`AsciiPlayerOptions::blocks`] and [`AsciiPlayerOptions::background_string`]
+    fn flags_render_mode(
+        options: &AsciiPlayerOptions,
+        converter_options: &AsciiArtConverterOptions,
+    ) -> RenderMode {
+        match (
+            options.background_string.to_owned(),
+            options.braille,
+            options.half_block,
+            options.blocks,
+        ) {
+            (Some(string), _, _, _) => RenderMode::BackgroundString {
+                string,
+                options: BackgroundStringArtConverterOptions {
+                    color_mode: options.color_mode,
+                    dithering: options.dithering,
+                    threshold: options.threshold_mode,
+                    lightness_model: options.lightness_model,
+                },
+            },
+            (None, true, _, _) => RenderMode::Braille(BrailleArtConverterOptions {
+                color_mode: options.color_mode,
+                dithering: options.dithering,
+                threshold: options.threshold_mode,
+                cell_color: options.cell_color,
+                lightness_model: options.lightness_model,
+            }),
+            (None, false, true, _) => RenderMode::HalfBlock(HalfBlockArtConverterOptions {
+                color_mode: options.color_mode,
+                lightness_model: options.lightness_model,
+            }),
+            (None, false, false, Some(kind)) => RenderMode::Blocks(BlockArtConverterOptions {
+                kind,
+                color_mode: options.color_mode,
+                cell_color: options.cell_color,
+                lightness_model: options.lightness_model,
+            }),
+            (None, false, false, None) => RenderMode::Ascii(converter_options.to_owned()),
+        }
+    }
+
+    /// Renders frame using [`AsciiPlayerOptions::output`]
+    ///
+    /// Terminal graphics protocols get image scaled to fill the same amount of cells as ASCII art would do,
+    /// or the terminal width, when neither width nor height is set
+    pub fn render_player_frame(
+        path: &PathBuf,
+        options: &AsciiPlayerOptions,
+        converter_options: &AsciiArtConverterOptions,
+    ) -> Result<PlayerFrame, AsciiPlayerError> {
+        AsciiPlayer::render_sequence_frame(
+            path,
+            options,
+            converter_options,
+            &SequenceContext::default(),
+        )
+    }
+
+    /// Same as [`AsciiPlayer::render_player_frame`], but with settings of whole sequence
+    fn render_sequence_frame(
+        path: &PathBuf,
+        options: &AsciiPlayerOptions,
+        converter_options: &AsciiArtConverterOptions,
+        sequence: &SequenceContext,
+    ) -> Result<PlayerFrame, AsciiPlayerError> {
+        let processed_img = AsciiPlayer::open_frame(path, options, sequence)?;
+
+        if options.output == OutputMode::Text {
+            return Ok(PlayerFrame::Text(AsciiPlayer::convert_frame(
+                &processed_img,
+                options,
+                converter_options,
+            )?));
+        }
+
+        // Without size given, image fills terminal width instead of taking a cell per pixel
+        let width = match (options.width, options.height) {
+            (None, None) => Some(terminal_columns()),
+            _ => options.width,
+        };
+        let (columns, rows) = calc_new_size(
+            width,
             options.height,
+            processed_img.width(),
+            processed_img.height(),
             options.font_ratio,
-            options.filter,
         );
+        let (cell_width, cell_height) = terminal_cell_size(options.font_ratio);
 
-        let ascii_art = match (options.background_string.as_deref(), options.braille) {
-            (Some(background_string), _) => {
-                prepared_img.background_string_art(background_string, options.colored)?
-            }
-            (None, true) => prepared_img.braille_art(options.colored)?,
-            (None, false) => prepared_img.ascii_art(converter_options)?,
+        let prepared_img = options.alpha_mode.apply_image(processed_img.resize_exact(
+            columns * cell_width,
+            rows * cell_height,
+            options.filter,
+        ));
+
+        let escape = match options.output {
+            OutputMode::Text => unreachable!(),
+            OutputMode::Kitty => prepared_img
+                .kitty_graphics(&KittyGraphicsOptions {
+                    columns: Some(columns),
+                    rows: Some(rows),
+                    move_cursor: false,
+                    ..Default::default()
+                })
+                .to_string(),
+            OutputMode::Sixel => prepared_img.sixel(&SixelOptions::default()).to_string(),
+            OutputMode::Iterm => prepared_img.iterm_image(&ItermImageOptions {
+                columns: Some(columns),
+                rows: Some(rows),
+                ..Default::default()
+            })?,
         };
 
-        Ok(ascii_art)
+        Ok(PlayerFrame::Graphics { escape, rows })
     }
 
     /// Play paths as ASCII arts
@@ -113,21 +306,27 @@ impl AsciiPlayer {
         let mut first_frame = true;
 
         let converter_options = options.to_owned().into();
+        let sequence = AsciiPlayer::sequence_context(paths, options)?;
 
         loop {
             for path in paths.iter() {
                 let start = Instant::now();
 
-                let ascii_art = AsciiPlayer::render_frame(path, options, &converter_options)?;
+                let frame = AsciiPlayer::render_sequence_frame(
+                    path,
+                    options,
+                    &converter_options,
+                    &sequence,
+                )?;
 
                 if !first_frame {
-                    execute!(stdout(), MoveUp(ascii_art.height.try_into().unwrap()))
+                    execute!(stdout(), MoveUp(frame.height().try_into().unwrap()))
                         .unwrap_or_default();
                 } else {
                     first_frame = false;
                 }
 
-                println!("{}", ascii_art);
+                println!("{}", frame);
 
                 while options.frame_time > start.elapsed().as_millis().try_into().unwrap() {}
             }
@@ -140,12 +339,65 @@ impl AsciiPlayer {
         Ok(())
     }
 
+    /// Find settings shared by all frames, when [`AsciiPlayerOptions::trim_scope`] or
+    /// [`AsciiPlayerOptions::tone_scope`] is sequence
+    ///
+    /// Frames are opened before playing them, once for union of their borders and once for
+    /// their histograms
+    fn sequence_context(
+        paths: &[PathBuf],
+        options: &AsciiPlayerOptions,
+    ) -> Result<SequenceContext, AsciiPlayerError> {
+        let mut sequence = SequenceContext::default();
+
+        if let (Some(tolerance), TrimScope::Sequence) = (options.trim, options.trim_scope) {
+            #[cfg(feature = "rayon")]
+            let iter = paths.into_par_iter();
+            #[cfg(not(feature = "rayon"))]
+            let iter = paths.iter();
+
+            let bounds = iter
+                .map(|path| {
+                    let img = AsciiPlayer::open_transformed_frame(path, options)?;
+                    Ok(content_bounds(&img, tolerance))
+                })
+                .collect::<Result<Vec<_>, AsciiPlayerError>>()?;
+
+            sequence.bounds = bounds
+                .into_iter()
+                .flatten()
+                .reduce(|bounds, other| bounds.union(&other));
+        }
+
+        if options.tone_scope == ToneScope::Sequence && options.tone_mapping != ToneMapping::None {
+            #[cfg(feature = "rayon")]
+            let iter = paths.into_par_iter();
+            #[cfg(not(feature = "rayon"))]
+            let iter = paths.iter();
+
+            let histograms = iter
+                .map(|path| {
+                    let img = AsciiPlayer::decode_frame(path, options, &sequence)?;
+                    Ok(options.tone_mapping.histogram(&img))
+                })
+                .collect::<Result<Vec<_>, AsciiPlayerError>>()?;
+
+            sequence.tone_map = histograms
+                .into_iter()
+                .reduce(|histogram, other| histogram.merge(&other))
+                .map(|histogram| options.tone_mapping.tone_map(&histogram));
+        }
+
+        Ok(sequence)
+    }
+
     /// Convert paths to of ASCII arts
     fn pre_render(
         paths: &[PathBuf],
         options: &AsciiPlayerOptions,
-    ) -> Result<Vec<AsciiArt>, AsciiPlayerError> {
+    ) -> Result<Vec<PlayerFrame>, AsciiPlayerError> {
         let converter_options = options.to_owned().into();
+        let sequence = AsciiPlayer::sequence_context(paths, options)?;
 
         #[cfg(feature = "rayon")]
         let iter = paths.into_par_iter();
@@ -159,8 +411,10 @@ impl AsciiPlayer {
 
         let frames = iter
             .progress_with_style(progress_bar_style)
-            .map(|path| AsciiPlayer::render_frame(path, options, &converter_options))
-            .collect::<Result<Vec<AsciiArt>, AsciiPlayerError>>()?;
+            .map(|path| {
+                AsciiPlayer::render_sequence_frame(path, options, &converter_options, &sequence)
+            })
+            .collect::<Result<Vec<PlayerFrame>, AsciiPlayerError>>()?;
 
         Ok(frames)
     }
@@ -175,17 +429,17 @@ impl AsciiPlayer {
         let frames = AsciiPlayer::pre_render(paths, options)?;
 
         loop {
-            frames.iter().for_each(|ascii_art| {
+            frames.iter().for_each(|frame| {
                 let start = Instant::now();
 
                 if !first_frame {
-                    execute!(stdout(), MoveUp(ascii_art.height.try_into().unwrap()))
+                    execute!(stdout(), MoveUp(frame.height().try_into().unwrap()))
                         .unwrap_or_default();
                 } else {
                     first_frame = false;
                 }
 
-                println!("{}", ascii_art);
+                println!("{}", frame);
 
                 while options.frame_time > start.elapsed().as_millis().try_into().unwrap() {}
             });
@@ -217,6 +471,8 @@ impl AsciiPlayer {
     /// assert!(AsciiPlayer::play(&paths, &options).is_ok())
     /// ```
     pub fn play(paths: &[PathBuf], options: &AsciiPlayerOptions) -> Result<(), AsciiPlayerError> {
+        options.validate()?;
+
         if options.pre_render {
             return AsciiPlayer::play_pre_rendered_frames(paths, options);
         }
@@ -225,6 +481,98 @@ impl AsciiPlayer {
     }
 }
 
+/// Settings shared by all frames, found before playing them
+#[derive(Debug, Default, Clone)]
+struct SequenceContext {
+    /// Union of subject bounds of all frames
+    bounds: Option<Bounds>,
+    /// Tone map built from histograms of all frames
+    tone_map: Option<ToneMap>,
+}
+
+/// Size of terminal cell in pixels
+///
+/// Falls back to [`DEFAULT_CELL_WIDTH`] and font ratio, when terminal doesn't report its size in pixels
+pub fn terminal_cell_size(font_ratio: f64) -> (u32, u32) {
+    match window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => (
+            (size.width / size.columns).max(1) as u32,
+            (size.height / size.rows).max(1) as u32,
+        ),
+        _ => (
+            DEFAULT_CELL_WIDTH,
+            ((DEFAULT_CELL_WIDTH as f64 / font_ratio).round() as u32).max(1),
+        ),
+    }
+}
+
+/// Width of terminal in cells
+///
+/// Falls back to [`DEFAULT_TERMINAL_COLUMNS`], when terminal doesn't report its size
+pub fn terminal_columns() -> u32 {
+    match window_size() {
+        Ok(size) if size.columns > 0 => size.columns as u32,
+        _ => DEFAULT_TERMINAL_COLUMNS,
+    }
+}
+
+/// Way of showing frames in terminal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum OutputMode {
+    /// Print ASCII art
+    #[default]
+    Text,
+    /// Show image using kitty graphics protocol
+    Kitty,
+    /// Show image using sixel graphics
+    Sixel,
+    /// Show image using iTerm2 inline images protocol
+    Iterm,
+}
+
+/// Frame rendered by [`AsciiPlayer`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlayerFrame {
+    /// ASCII art
+    Text(AsciiArt),
+    /// Escape sequence of terminal graphics protocol
+    Graphics {
+        /// Escape sequence showing the image
+        escape: String,
+        /// Amount of rows taken by the image
+        rows: u32,
+    },
+}
+
+impl PlayerFrame {
+    /// Amount of rows taken by frame
+    pub fn height(&self) -> u32 {
+        match self {
+            PlayerFrame::Text(ascii_art) => ascii_art.height,
+            PlayerFrame::Graphics { rows, .. } => *rows,
+        }
+    }
+}
+
+impl fmt::Display for PlayerFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlayerFrame::Text(ascii_art) => write!(f, "{}", ascii_art),
+            PlayerFrame::Graphics { escape, rows } => {
+                let skipped_rows = rows.saturating_sub(1) as usize;
+
+                // Reserve rows, so terminal doesn't scroll while showing image
+                if skipped_rows > 0 {
+                    write!(f, "{}\x1b[{}A", "\n".repeat(skipped_rows), skipped_rows)?;
+                }
+
+                // Protocols move cursor differently, so it is restored and rows are skipped like after the text
+                write!(f, "\x1b7{}\x1b8{}", escape, "\n".repeat(skipped_rows))
+            }
+        }
+    }
+}
+
 /// Options of player to convert and play frames
 #[derive(Debug, Clone, PartialEq)]
 pub struct AsciiPlayerOptions {
@@ -234,8 +582,14 @@ pub struct AsciiPlayerOptions {
     pub height: Option<u32>,
     /// String to represent lightness of pixels
     pub ascii_string: String,
-    /// Make ASCII art colored
-    pub colored: bool,
+    /// Color depth of ASCII art
+    pub color_mode: ColorMode,
+    /// Way of applying pixel colors to characters
+    pub color_style: ColorStyle,
+    /// Dithering used for picking characters and lit pixels
+    pub dithering: Dithering,
+    /// Way of calculating lightness of pixels
+    pub lightness_model: LightnessModel,
     /// Time to sleep after each frame
     pub frame_time: u64,
     /// Render before starting slideshow
@@ -246,12 +600,38 @@ pub struct AsciiPlayerOptions {
     pub looped: bool,
     /// Filter used for resizing
     pub filter: FilterType,
+    /// Crop, rotation and flip applied to frames before resizing
+    pub transform: Transform,
+    /// Remove uniform borders of frames, with tolerance (from 0.0 to 1.0) of their color
+    pub trim: Option<f32>,
+    /// Frames used for finding borders of [`AsciiPlayerOptions::trim`]
+    pub trim_scope: TrimScope,
+    /// Adjustments applied to frames before resizing
+    pub preprocessing: Preprocessing,
+    /// Stretching of frames lightness, applied after preprocessing
+    pub tone_mapping: ToneMapping,
+    /// Frames used for building tone curves of [`AsciiPlayerOptions::tone_mapping`]
+    pub tone_scope: ToneScope,
     /// Threshold block radius
     pub threshold: Option<u32>,
+    /// Threshold for lit pixels of braille and background string
+    pub threshold_mode: ThresholdMode,
+    /// Way of picking color of braille and block characters
+    pub cell_color: CellColor,
+    /// Way of showing transparent pixels
+    pub alpha_mode: AlphaMode,
     /// Use braille characters for displaying image
     pub braille: bool,
+    /// Use half block characters for displaying image, two pixels per character
+    pub half_block: bool,
+    /// Use quadrant or sextant block characters for displaying image
+    pub blocks: Option<BlockKind>,
     /// Text to show as background on light pixels
     pub background_string: Option<String>,
+    /// Renderer of frames, overrides `braille`, `half_block`, `blocks` and `background_string`
+    pub render_mode: Option<RenderMode>,
+    /// Way of showing frames in terminal
+    pub output: OutputMode,
 }
 
 impl Default for AsciiPlayerOptions {
@@ -260,16 +640,107 @@ impl Default for AsciiPlayerOptions {
             width: None,
             height: None,
             ascii_string: DEFAULT_ASCII_STRING.to_owned(),
-            colored: false,
+            color_mode: ColorMode::None,
+            color_style: ColorStyle::Foreground,
+            dithering: Dithering::None,
+            lightness_model: LightnessModel::default(),
             frame_time: 0,
             pre_render: false,
             font_ratio: DEFAULT_FONT_RATIO,
             looped: false,
             filter: FilterType::Triangle,
+            transform: Transform::default(),
+            trim: None,
+            trim_scope: TrimScope::Frame,
+            preprocessing: Preprocessing::default(),
+            tone_mapping: ToneMapping::None,
+            tone_scope: ToneScope::Frame,
             threshold: None,
+            threshold_mode: ThresholdMode::default(),
+            cell_color: CellColor::MeanLit,
+            alpha_mode: AlphaMode::Darken,
             braille: false,
+            half_block: false,
+            blocks: None,
             background_string: None,
+            render_mode: None,
+            output: OutputMode::Text,
+        }
+    }
+}
+
+impl AsciiPlayerOptions {
+    /// Check options before opening any frames
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::renderers::{
+    ///     glyph::{GlyphArtConverterOptions, GlyphSet},
+    ///     renderer::RenderMode,
+    /// };
+    /// use tapciify::utils::player::{AsciiPlayerOptions, AsciiPlayerOptionsError};
+    ///
+    /// assert!(AsciiPlayerOptions::default().validate().is_ok());
+    ///
+    /// let options = AsciiPlayerOptions {
+    ///     width: Some(0),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(options.validate(), Err(AsciiPlayerOptionsError::ZeroSize));
+    ///
+    /// let options = AsciiPlayerOptions {
+    ///     trim: Some(2.0),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(options.validate(), Err(AsciiPlayerOptionsError::TrimTolerance(2.0)));
+    ///
+    /// let options = AsciiPlayerOptions {
+    ///     ascii_string: String::new(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(matches!(options.validate(), Err(AsciiPlayerOptionsError::Renderer(_))));
+    ///
+    /// let options = AsciiPlayerOptions {
+    ///     render_mode: Some(RenderMode::Glyph(GlyphArtConverterOptions {
+    ///         glyphs: GlyphSet::builtin(" .:😀", 4, 8).unwrap(),
+    ///         ..Default::default()
+    ///     })),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     options.validate(),
+    ///     Err(AsciiPlayerOptionsError::MissingGlyphs(vec!['😀']))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), AsciiPlayerOptionsError> {
+        if self.width == Some(0) || self.height == Some(0) {
+            return Err(AsciiPlayerOptionsError::ZeroSize);
         }
+        if !self.font_ratio.is_finite() || self.font_ratio <= 0.0 {
+            return Err(AsciiPlayerOptionsError::FontRatio(self.font_ratio));
+        }
+        if let Some(tolerance) = self
+            .trim
+            .filter(|tolerance| !(0.0..=1.0).contains(tolerance))
+        {
+            return Err(AsciiPlayerOptionsError::TrimTolerance(tolerance));
+        }
+
+        let render_mode = self
+            .render_mode
+            .clone()
+            .unwrap_or_else(|| AsciiPlayer::flags_render_mode(self, &self.to_owned().into()));
+
+        if let RenderMode::Glyph(options) = &render_mode {
+            if !options.glyphs.missing.is_empty() {
+                return Err(AsciiPlayerOptionsError::MissingGlyphs(
+                    options.glyphs.missing.to_owned(),
+                ));
+            }
+        }
+
+        Ok(render_mode.validate()?)
     }
 }
 
@@ -277,7 +748,10 @@ impl From<AsciiPlayerOptions> for AsciiArtConverterOptions {
     fn from(o: AsciiPlayerOptions) -> AsciiArtConverterOptions {
         AsciiArtConverterOptions {
             ascii_string: o.ascii_string,
-            colored: o.colored,
+            color_mode: o.color_mode,
+            color_style: o.color_style,
+            dithering: o.dithering,
+            lightness_model: o.lightness_model,
         }
     }
 }
@@ -290,6 +764,8 @@ pub enum AsciiPlayerError {
     // TODO: Rename into AsciiArtConverter
     /// Error caused by [`AsciiArtConverter`] ([`AsciiArtConverterError`])
     AsciiConverter(AsciiArtConverterError),
+    /// Error caused by invalid [`AsciiPlayerOptions`]
+    Options(AsciiPlayerOptionsError),
 }
 
 impl error::Error for AsciiPlayerError {}
@@ -303,6 +779,7 @@ impl fmt::Display for AsciiPlayerError {
             AsciiPlayerError::AsciiConverter(err) => {
                 write!(f, "ASCII art converter error: {}", err)
             }
+            AsciiPlayerError::Options(err) => write!(f, "Options error: {}", err),
         }
     }
 }
@@ -330,3 +807,64 @@ impl From<SizeError> for AsciiPlayerError {
         AsciiPlayerError::AsciiConverter(AsciiArtConverterError::SizeError(err))
     }
 }
+
+impl From<AsciiPlayerOptionsError> for AsciiPlayerError {
+    fn from(err: AsciiPlayerOptionsError) -> AsciiPlayerError {
+        AsciiPlayerError::Options(err)
+    }
+}
+
+/// Error caused by [`AsciiPlayerOptions::validate`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsciiPlayerOptionsError {
+    /// Width or height is zero
+    ZeroSize,
+    /// Font ratio isn't a positive number
+    FontRatio(f64),
+    /// Trim tolerance is out of range from 0.0 to 1.0
+    TrimTolerance(f32),
+    /// Characters of glyph mode, which font can't render
+    MissingGlyphs(Vec<char>),
+    /// Options of renderer are invalid
+    Renderer(AsciiArtConverterError),
+}
+
+impl error::Error for AsciiPlayerOptionsError {}
+
+impl fmt::Display for AsciiPlayerOptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsciiPlayerOptionsError::ZeroSize => {
+                write!(f, "width and height should be greater than 0")
+            }
+            AsciiPlayerOptionsError::FontRatio(font_ratio) => {
+                write!(
+                    f,
+                    "font ratio should be a positive number, got {}",
+                    font_ratio
+                )
+            }
+            AsciiPlayerOptionsError::TrimTolerance(tolerance) => {
+                write!(
+                    f,
+                    "trim tolerance should be from 0.0 to 1.0, got {}",
+                    tolerance
+                )
+            }
+            AsciiPlayerOptionsError::MissingGlyphs(characters) => {
+                write!(
+                    f,
+                    "font has no glyphs for characters: {}",
+                    characters.iter().collect::<String>()
+                )
+            }
+            AsciiPlayerOptionsError::Renderer(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<AsciiArtConverterError> for AsciiPlayerOptionsError {
+    fn from(err: AsciiArtConverterError) -> AsciiPlayerOptionsError {
+        AsciiPlayerOptionsError::Renderer(err)
+    }
+}
@@ -0,0 +1,271 @@
+//! Utils for adjusting images before resizing, like in image editors
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use image::imageops::FilterType;
+//! use tapciify::{
+//!     prelude::*,
+//!     utils::{preprocess::Preprocessing, resize::DEFAULT_FONT_RATIO},
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//!
+//! let preprocessing = Preprocessing::new().contrast(1.3).gamma(1.2).sharpen(1.0);
+//!
+//! let result = preprocessing
+//!     .apply(img)
+//!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+//!     .ascii_art(&AsciiArtConverterOptions::default())?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error, fmt, str::FromStr};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Single step of [`Preprocessing`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Adjustment {
+    /// Add to every channel, from -1.0 to 1.0
+    Brightness(f32),
+    /// Scale distance of channels from middle gray, 1.0 keeps image unchanged
+    Contrast(f32),
+    /// Gamma correction, values above 1.0 lighten midtones
+    Gamma(f32),
+    /// Scale distance of colors from their gray, 0.0 makes image grayscale
+    Saturation(f32),
+    /// Unsharp mask with blur of sigma
+    Sharpen(f32),
+    /// Gaussian blur with sigma
+    Blur(f32),
+    /// Invert colors
+    Invert,
+    /// Reduce every channel to levels count, at least 2
+    Posterize(u8),
+}
+
+impl Adjustment {
+    /// Apply adjustment to image
+    pub fn apply(&self, mut img: image::DynamicImage) -> image::DynamicImage {
+        match *self {
+            Adjustment::Sharpen(sigma) => img.unsharpen(sigma.max(f32::EPSILON), 0),
+            Adjustment::Blur(sigma) => img.blur(sigma.max(f32::EPSILON)),
+            Adjustment::Invert => {
+                img.invert();
+                img
+            }
+            adjustment => {
+                let mut img = img.to_rgba8();
+
+                #[cfg(feature = "rayon")]
+                let iter = img.par_pixels_mut();
+                #[cfg(not(feature = "rayon"))]
+                let iter = img.pixels_mut();
+
+                iter.for_each(|pixel| {
+                    let rgb = [pixel[0], pixel[1], pixel[2]].map(|channel| channel as f32 / 255.0);
+                    let [r, g, b] = adjustment.adjust_color(rgb);
+
+                    pixel.0 = [r, g, b, pixel[3]];
+                });
+
+                img.into()
+            }
+        }
+    }
+
+    /// Adjust color of single pixel, channels are from 0.0 to 1.0
+    fn adjust_color(&self, rgb: [f32; 3]) -> [u8; 3] {
+        let rgb = match *self {
+            Adjustment::Brightness(brightness) => rgb.map(|channel| channel + brightness),
+            Adjustment::Contrast(contrast) => rgb.map(|channel| (channel - 0.5) * contrast + 0.5),
+            Adjustment::Gamma(gamma) => {
+                rgb.map(|channel| channel.powf(1.0 / gamma.max(f32::EPSILON)))
+            }
+            Adjustment::Saturation(saturation) => {
+                let gray = 0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2];
+                rgb.map(|channel| gray + (channel - gray) * saturation)
+            }
+            Adjustment::Posterize(levels) => {
+                let steps = levels.max(2) as f32 - 1.0;
+                rgb.map(|channel| (channel * steps).round() / steps)
+            }
+            Adjustment::Sharpen(_) | Adjustment::Blur(_) | Adjustment::Invert => rgb,
+        };
+
+        rgb.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+}
+
+impl FromStr for Adjustment {
+    type Err = AdjustmentError;
+
+    /// Parse `invert` or `<name>=<value>`, where name is `brightness`, `contrast`, `gamma`,
+    /// `saturation`, `sharpen`, `blur` or `posterize`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::preprocess::Adjustment;
+    ///
+    /// assert_eq!("invert".parse(), Ok(Adjustment::Invert));
+    /// assert_eq!("contrast=1.5".parse(), Ok(Adjustment::Contrast(1.5)));
+    /// assert_eq!("posterize=4".parse(), Ok(Adjustment::Posterize(4)));
+    /// assert!("gamma=0".parse::<Adjustment>().is_err());
+    /// assert!("sepia=1".parse::<Adjustment>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Adjustment, AdjustmentError> {
+        let invalid = || AdjustmentError(s.to_owned());
+
+        let (name, value) = match s.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (s.trim(), None),
+        };
+        let number = || {
+            value
+                .and_then(|value| value.parse::<f32>().ok())
+                .filter(|value| value.is_finite())
+                .ok_or_else(invalid)
+        };
+        let positive = || {
+            number().and_then(|value| match value > 0.0 {
+                true => Ok(value),
+                false => Err(invalid()),
+            })
+        };
+
+        match name {
+            "brightness" => Ok(Adjustment::Brightness(number()?)),
+            "contrast" => Ok(Adjustment::Contrast(number()?)),
+            "gamma" => Ok(Adjustment::Gamma(positive()?)),
+            "saturation" => Ok(Adjustment::Saturation(number()?)),
+            "sharpen" => Ok(Adjustment::Sharpen(positive()?)),
+            "blur" => Ok(Adjustment::Blur(positive()?)),
+            "invert" if value.is_none() => Ok(Adjustment::Invert),
+            "posterize" => value
+                .and_then(|value| value.parse::<u8>().ok())
+                .filter(|levels| *levels >= 2)
+                .map(Adjustment::Posterize)
+                .ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Error caused by invalid [`Adjustment`] string
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct AdjustmentError(pub String);
+
+impl error::Error for AdjustmentError {}
+
+impl fmt::Display for AdjustmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid adjustment `{}`, expected `invert` or `<name>=<value>`, like `contrast=1.5`",
+            self.0
+        )
+    }
+}
+
+/// Ordered list of [`Adjustment`], applied one after another
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Preprocessing {
+    /// Adjustments in order of applying
+    pub adjustments: Vec<Adjustment>,
+}
+
+impl Preprocessing {
+    /// Create empty preprocessing, which keeps images unchanged
+    pub fn new() -> Preprocessing {
+        Preprocessing::default()
+    }
+
+    /// Add [`Adjustment`] to the end
+    pub fn then(mut self, adjustment: Adjustment) -> Preprocessing {
+        self.adjustments.push(adjustment);
+
+        self
+    }
+
+    /// Add [`Adjustment::Brightness`]
+    pub fn brightness(self, brightness: f32) -> Preprocessing {
+        self.then(Adjustment::Brightness(brightness))
+    }
+
+    /// Add [`Adjustment::Contrast`]
+    pub fn contrast(self, contrast: f32) -> Preprocessing {
+        self.then(Adjustment::Contrast(contrast))
+    }
+
+    /// Add [`Adjustment::Gamma`]
+    pub fn gamma(self, gamma: f32) -> Preprocessing {
+        self.then(Adjustment::Gamma(gamma))
+    }
+
+    /// Add [`Adjustment::Saturation`]
+    pub fn saturation(self, saturation: f32) -> Preprocessing {
+        self.then(Adjustment::Saturation(saturation))
+    }
+
+    /// Add [`Adjustment::Sharpen`]
+    pub fn sharpen(self, sigma: f32) -> Preprocessing {
+        self.then(Adjustment::Sharpen(sigma))
+    }
+
+    /// Add [`Adjustment::Blur`]
+    pub fn blur(self, sigma: f32) -> Preprocessing {
+        self.then(Adjustment::Blur(sigma))
+    }
+
+    /// Add [`Adjustment::Invert`]
+    pub fn invert(self) -> Preprocessing {
+        self.then(Adjustment::Invert)
+    }
+
+    /// Add [`Adjustment::Posterize`]
+    pub fn posterize(self, levels: u8) -> Preprocessing {
+        self.then(Adjustment::Posterize(levels))
+    }
+
+    /// Checks if there is nothing to apply
+    pub fn is_empty(&self) -> bool {
+        self.adjustments.is_empty()
+    }
+
+    /// Apply adjustments in order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{DynamicImage, Rgb, RgbImage};
+    /// use tapciify::utils::preprocess::Preprocessing;
+    ///
+    /// let img = DynamicImage::from(RgbImage::from_pixel(1, 1, Rgb([100, 150, 200])));
+    ///
+    /// let result = Preprocessing::new().invert().apply(img.clone());
+    /// assert_eq!(result.to_rgb8().get_pixel(0, 0), &Rgb([155, 105, 55]));
+    ///
+    /// let result = Preprocessing::new().saturation(0.0).posterize(2).apply(img);
+    /// assert_eq!(result.to_rgb8().get_pixel(0, 0), &Rgb([255, 255, 255]));
+    /// ```
+    pub fn apply(&self, img: image::DynamicImage) -> image::DynamicImage {
+        self.adjustments
+            .iter()
+            .fold(img, |img, adjustment| adjustment.apply(img))
+    }
+}
+
+impl From<Vec<Adjustment>> for Preprocessing {
+    fn from(adjustments: Vec<Adjustment>) -> Preprocessing {
+        Preprocessing { adjustments }
+    }
+}
@@ -5,6 +5,23 @@ use image::imageops;
 /// Consolas font family aspect ratio
 pub const DEFAULT_FONT_RATIO: f64 = 11.0 / 24.0;
 
+/// Width of double-width characters, like emoji, in terminal columns
+pub const WIDE_CELL_COLUMNS: u32 = 2;
+
+/// Font ratio of cells spanning several terminal columns, like double-width emoji
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::resize::{DEFAULT_FONT_RATIO, WIDE_CELL_COLUMNS, cell_font_ratio};
+///
+/// assert_eq!(cell_font_ratio(0.5, WIDE_CELL_COLUMNS), 1.0);
+/// assert_eq!(cell_font_ratio(DEFAULT_FONT_RATIO, 1), DEFAULT_FONT_RATIO);
+/// ```
+pub const fn cell_font_ratio(font_ratio: f64, columns: u32) -> f64 {
+    font_ratio * columns as f64
+}
+
 /// Trait for resizing images and counting in font ratio
 pub trait CustomRatioResize {
     /// Resize [`image::DynamicImage`] to your sizes
@@ -40,29 +57,60 @@ impl CustomRatioResize for image::DynamicImage {
         font_ratio: f64,
         filter: imageops::FilterType,
     ) -> image::DynamicImage {
-        let (new_width, new_height) = match (width, height) {
-            (None, None) => return self.to_owned(),
-            (None, Some(height)) => (
-                calc_new_width(height, self.width(), self.height(), font_ratio),
-                height,
-            ),
-            (Some(width), None) => (
-                width,
-                calc_new_height(width, self.width(), self.height(), font_ratio),
-            ),
-            (Some(width), Some(height)) => (width, height),
-        };
+        if width.is_none() && height.is_none() {
+            return self.to_owned();
+        }
+
+        let (new_width, new_height) =
+            calc_new_size(width, height, self.width(), self.height(), font_ratio);
 
         self.resize_exact(new_width, new_height, filter)
     }
 }
 
-/// Calculate new width from aspect ratio and new height
+/// Calculate new width from aspect ratio and new height, at least 1
 pub fn calc_new_width(new_height: u32, width: u32, height: u32, font_ratio: f64) -> u32 {
-    ((new_height * width) as f64 / (height as f64 * font_ratio)) as u32
+    ((new_height as f64 * width as f64 / (height as f64 * font_ratio)) as u32).max(1)
 }
 
-/// Calculate new height from aspect ratio and new width
+/// Calculate new height from aspect ratio and new width, at least 1
 pub fn calc_new_height(new_width: u32, width: u32, height: u32, font_ratio: f64) -> u32 {
-    (new_width as f64 * font_ratio * height as f64 / width as f64) as u32
+    ((new_width as f64 * font_ratio * height as f64 / width as f64) as u32).max(1)
+}
+
+/// Calculate new size from aspect ratio and new width and/or height
+///
+/// When both `new_width` and `new_height` are [`None`], will return the original size
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::resize::calc_new_size;
+///
+/// assert_eq!(calc_new_size(Some(64), None, 128, 128, 0.5), (64, 32));
+/// assert_eq!(calc_new_size(None, Some(32), 128, 128, 0.5), (64, 32));
+/// assert_eq!(calc_new_size(None, None, 128, 128, 0.5), (128, 128));
+///
+/// // Very wide images keep at least one row
+/// assert_eq!(calc_new_size(Some(4), None, 1000, 10, 0.5), (4, 1));
+/// ```
+pub fn calc_new_size(
+    new_width: Option<u32>,
+    new_height: Option<u32>,
+    width: u32,
+    height: u32,
+    font_ratio: f64,
+) -> (u32, u32) {
+    match (new_width, new_height) {
+        (None, None) => (width, height),
+        (None, Some(new_height)) => (
+            calc_new_width(new_height, width, height, font_ratio),
+            new_height,
+        ),
+        (Some(new_width), None) => (
+            new_width,
+            calc_new_height(new_width, width, height, font_ratio),
+        ),
+        (Some(new_width), Some(new_height)) => (new_width, new_height),
+    }
 }
@@ -1,7 +1,11 @@
-//! Utils used in [`crate::background_string`] and [`crate::braille`]
+//! Utils used in [`crate::renderers::background_string`] and [`crate::renderers::braille`]
+
+use std::{error, fmt, str::FromStr};
 
 use image::Pixel;
 
+use crate::{renderers::ascii::ToAsciiArtPixel, utils::lightness::LightnessModel};
+
 /// 1/2 threshold
 pub const DEFAULT_THRESHOLD: f32 = 0.5;
 
@@ -23,6 +27,25 @@ pub trait ThresholdPixel {
     /// # }
     /// ```
     fn threshold_pixel(&self, threshold: f32) -> bool;
+
+    /// Same as [`ThresholdPixel::threshold_pixel`], but lightness is calculated with
+    /// [`LightnessModel`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::{lightness::LightnessModel, threshold::ThresholdPixel};
+    ///
+    /// let pixel = image::Rgb::<u8>([0, 0, 255]);
+    /// assert!(!pixel.threshold_pixel_with(0.3, LightnessModel::Rec709));
+    /// assert!(pixel.threshold_pixel_with(0.3, LightnessModel::Hsp));
+    /// ```
+    fn threshold_pixel_with(&self, threshold: f32, model: LightnessModel) -> bool
+    where
+        Self: ToAsciiArtPixel,
+    {
+        self.lightness_with(model) > threshold
+    }
 }
 
 impl ThresholdPixel for image::Luma<u8> {
@@ -48,3 +71,172 @@ impl ThresholdPixel for image::Rgba<u8> {
         self.to_luma_alpha().threshold_pixel(threshold)
     }
 }
+
+/// Way of choosing threshold for binary renderers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThresholdMode {
+    /// Same threshold (from 0.0 to 1.0) for every pixel
+    Fixed(f32),
+    /// Global threshold, which splits lightness histogram into two classes best (Otsu's method)
+    Otsu,
+    /// Mean lightness of the character cell, so only local details are lit
+    LocalMean,
+}
+
+impl Default for ThresholdMode {
+    fn default() -> ThresholdMode {
+        ThresholdMode::Fixed(DEFAULT_THRESHOLD)
+    }
+}
+
+impl ThresholdMode {
+    /// Calculate threshold for every pixel, cells are `cell_width` x `cell_height` pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::threshold::ThresholdMode;
+    ///
+    /// let lightness = vec![0.1, 0.3, 0.2, 0.4];
+    ///
+    /// assert_eq!(ThresholdMode::Fixed(0.5).thresholds(&lightness, 4, 1, 2, 1), vec![0.5; 4]);
+    /// assert_eq!(
+    ///     ThresholdMode::LocalMean.thresholds(&lightness, 4, 1, 2, 1),
+    ///     vec![0.2, 0.2, 0.3, 0.3]
+    /// );
+    /// ```
+    pub fn thresholds(
+        &self,
+        lightness: &[f32],
+        width: u32,
+        height: u32,
+        cell_width: u32,
+        cell_height: u32,
+    ) -> Vec<f32> {
+        match self {
+            ThresholdMode::Fixed(threshold) => vec![*threshold; lightness.len()],
+            ThresholdMode::Otsu => vec![otsu_threshold(lightness); lightness.len()],
+            ThresholdMode::LocalMean => {
+                let cells_width = width.div_ceil(cell_width);
+                let cells_height = height.div_ceil(cell_height);
+
+                let mut sums = vec![(0.0, 0); (cells_width * cells_height) as usize];
+                let cell_index = |i: usize| {
+                    let (x, y) = (i as u32 % width, i as u32 / width);
+                    ((y / cell_height) * cells_width + x / cell_width) as usize
+                };
+
+                for (i, value) in lightness.iter().enumerate() {
+                    let (sum, count) = &mut sums[cell_index(i)];
+                    *sum += value;
+                    *count += 1;
+                }
+
+                (0..lightness.len())
+                    .map(|i| {
+                        let (sum, count) = sums[cell_index(i)];
+                        sum / count as f32
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl FromStr for ThresholdMode {
+    type Err = ThresholdModeError;
+
+    /// Parse threshold mode: `otsu`, `local-mean` or number from 0.0 to 1.0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::threshold::ThresholdMode;
+    ///
+    /// assert_eq!("otsu".parse(), Ok(ThresholdMode::Otsu));
+    /// assert_eq!("local-mean".parse(), Ok(ThresholdMode::LocalMean));
+    /// assert_eq!("0.3".parse(), Ok(ThresholdMode::Fixed(0.3)));
+    /// assert!("1.5".parse::<ThresholdMode>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<ThresholdMode, ThresholdModeError> {
+        match s {
+            "otsu" => Ok(ThresholdMode::Otsu),
+            "local-mean" => Ok(ThresholdMode::LocalMean),
+            _ => match s.parse::<f32>() {
+                Ok(threshold) if (0.0..=1.0).contains(&threshold) => {
+                    Ok(ThresholdMode::Fixed(threshold))
+                }
+                _ => Err(ThresholdModeError),
+            },
+        }
+    }
+}
+
+/// Error caused by invalid [`ThresholdMode`] string
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ThresholdModeError;
+
+impl error::Error for ThresholdModeError {}
+
+impl fmt::Display for ThresholdModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "threshold should be `otsu`, `local-mean` or number from 0.0 to 1.0"
+        )
+    }
+}
+
+/// Find threshold, which maximizes variance between dark and light pixels (Otsu's method)
+///
+/// Falls back to [`DEFAULT_THRESHOLD`] for images having single lightness
+///
+/// # Examples
+///
+/// ```
+/// use tapciify::utils::threshold::{DEFAULT_THRESHOLD, otsu_threshold};
+///
+/// let lightness = vec![0.0, 0.1, 0.1, 0.8, 0.9];
+/// let threshold = otsu_threshold(&lightness);
+///
+/// assert_eq!(lightness.iter().filter(|value| **value > threshold).count(), 2);
+/// assert_eq!(otsu_threshold(&[0.1; 4]), DEFAULT_THRESHOLD);
+/// ```
+pub fn otsu_threshold(lightness: &[f32]) -> f32 {
+    let mut histogram = [0u32; 256];
+    for value in lightness {
+        histogram[(value.clamp(0.0, 1.0) * 255.0).round() as usize] += 1;
+    }
+
+    let total = lightness.len() as f64;
+    let total_sum = histogram
+        .iter()
+        .enumerate()
+        .map(|(level, count)| level as f64 * *count as f64)
+        .sum::<f64>();
+
+    let mut dark_count = 0.0;
+    let mut dark_sum = 0.0;
+    let mut best = (0.0, None);
+
+    for (level, count) in histogram.iter().enumerate() {
+        dark_count += *count as f64;
+        dark_sum += level as f64 * *count as f64;
+
+        let light_count = total - dark_count;
+        if dark_count == 0.0 || light_count == 0.0 {
+            continue;
+        }
+
+        let dark_mean = dark_sum / dark_count;
+        let light_mean = (total_sum - dark_sum) / light_count;
+        let variance = dark_count * light_count * (dark_mean - light_mean).powi(2);
+
+        if variance > best.0 {
+            best = (variance, Some(level));
+        }
+    }
+
+    best.1
+        .map_or(DEFAULT_THRESHOLD, |level| level as f32 / 255.0)
+}
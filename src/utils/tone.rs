@@ -0,0 +1,297 @@
+//! Utils for stretching lightness of images, so ASCII string covers the full range
+//!
+//! Tone curves are built from [`LightnessHistogram`], which can be merged across frames of
+//! video, so brightness doesn't pump between them
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use image::imageops::FilterType;
+//! use tapciify::{
+//!     prelude::*,
+//!     utils::{resize::DEFAULT_FONT_RATIO, tone::ToneMapping},
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/rin-shima.webp")?;
+//!
+//! let result = ToneMapping::Clahe
+//!     .apply(img)
+//!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+//!     .ascii_art(&AsciiArtConverterOptions::default())?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use image::Pixel;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Count of tiles per side of image in [`ToneMapping::Clahe`]
+pub const CLAHE_TILES: u32 = 8;
+
+/// Limit of histogram bins in [`ToneMapping::Clahe`], relative to their mean
+pub const CLAHE_CLIP_LIMIT: f32 = 2.0;
+
+/// Way of stretching lightness of images
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum ToneMapping {
+    /// Keep lightness unchanged
+    #[default]
+    None,
+    /// Stretch lightness from the darkest to the lightest pixel
+    AutoLevels,
+    /// Spread lightness evenly using global histogram equalisation
+    Equalize,
+    /// Contrast limited adaptive histogram equalisation, for details in both shadows and highlights
+    Clahe,
+}
+
+/// Frames used for building tone curves
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum ToneScope {
+    /// Build tone curve for every frame
+    #[default]
+    Frame,
+    /// Build single tone curve from all frames
+    Sequence,
+}
+
+impl ToneMapping {
+    /// Count of tiles per side of image
+    fn tiles(&self) -> u32 {
+        match self {
+            ToneMapping::Clahe => CLAHE_TILES,
+            _ => 1,
+        }
+    }
+
+    /// Collect histogram of image lightness, transparent pixels are skipped
+    pub fn histogram(&self, img: &image::DynamicImage) -> LightnessHistogram {
+        let tiles = self.tiles();
+        let mut histogram = LightnessHistogram::new(tiles, tiles);
+
+        if *self == ToneMapping::None || img.width() == 0 || img.height() == 0 {
+            return histogram;
+        }
+
+        let img = img.to_rgba8();
+        let tile_width = img.width().div_ceil(tiles);
+        let tile_height = img.height().div_ceil(tiles);
+
+        for (x, y, pixel) in img.enumerate_pixels().filter(|(_, _, pixel)| pixel[3] > 0) {
+            let tile = (y / tile_height * tiles + x / tile_width) as usize;
+            histogram.bins[tile][pixel.to_luma()[0] as usize] += 1;
+        }
+
+        histogram
+    }
+
+    /// Build tone curves from histogram
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{DynamicImage, GrayImage, Luma};
+    /// use tapciify::utils::tone::ToneMapping;
+    ///
+    /// let img = DynamicImage::from(GrayImage::from_fn(2, 1, |x, _| Luma([[64, 128][x as usize]])));
+    ///
+    /// let tone_map = ToneMapping::AutoLevels.tone_map(&ToneMapping::AutoLevels.histogram(&img));
+    /// assert_eq!(tone_map.apply(img).to_luma8().into_raw(), [0, 255]);
+    /// ```
+    pub fn tone_map(&self, histogram: &LightnessHistogram) -> ToneMap {
+        let luts = histogram
+            .bins
+            .iter()
+            .map(|bins| match self {
+                ToneMapping::None => IDENTITY_LUT,
+                ToneMapping::AutoLevels => auto_levels_lut(bins),
+                ToneMapping::Equalize => equalize_lut(bins),
+                ToneMapping::Clahe => equalize_lut(&clip_histogram(bins, CLAHE_CLIP_LIMIT)),
+            })
+            .collect();
+
+        ToneMap {
+            columns: histogram.columns,
+            rows: histogram.rows,
+            luts,
+        }
+    }
+
+    /// Stretch lightness of image using its own histogram
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{DynamicImage, GrayImage, Luma};
+    /// use tapciify::utils::tone::ToneMapping;
+    ///
+    /// let img = DynamicImage::from(GrayImage::from_fn(4, 1, |x, _| Luma([100 + x as u8])));
+    ///
+    /// let result = ToneMapping::Equalize.apply(img.clone()).to_luma8().into_raw();
+    /// assert_eq!(result, [0, 85, 170, 255]);
+    ///
+    /// let result = ToneMapping::None.apply(img.clone());
+    /// assert_eq!(result, img);
+    /// ```
+    pub fn apply(&self, img: image::DynamicImage) -> image::DynamicImage {
+        if *self == ToneMapping::None {
+            return img;
+        }
+
+        self.tone_map(&self.histogram(&img)).apply(img)
+    }
+}
+
+/// Histograms of lightness for tiles of image
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LightnessHistogram {
+    columns: u32,
+    rows: u32,
+    bins: Vec<[u32; 256]>,
+}
+
+impl LightnessHistogram {
+    /// Create empty histogram for grid of tiles
+    pub fn new(columns: u32, rows: u32) -> LightnessHistogram {
+        LightnessHistogram {
+            columns,
+            rows,
+            bins: vec![[0; 256]; (columns * rows) as usize],
+        }
+    }
+
+    /// Add counts of other histogram, made with the same [`ToneMapping`]
+    pub fn merge(mut self, other: &LightnessHistogram) -> LightnessHistogram {
+        for (bins, other_bins) in self.bins.iter_mut().zip(&other.bins) {
+            for (bin, other_bin) in bins.iter_mut().zip(other_bins) {
+                *bin += other_bin;
+            }
+        }
+
+        self
+    }
+}
+
+/// Tone curves for tiles of image, made by [`ToneMapping::tone_map`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ToneMap {
+    columns: u32,
+    rows: u32,
+    luts: Vec<[u8; 256]>,
+}
+
+impl ToneMap {
+    /// Map channels of image with tone curves, interpolated between centers of tiles
+    pub fn apply(&self, img: image::DynamicImage) -> image::DynamicImage {
+        let mut img = img.to_rgba8();
+        let width = img.width();
+
+        let tile_width = width.div_ceil(self.columns).max(1) as f32;
+        let tile_height = img.height().div_ceil(self.rows).max(1) as f32;
+
+        #[cfg(feature = "rayon")]
+        let iter = img.par_pixels_mut();
+        #[cfg(not(feature = "rayon"))]
+        let iter = img.pixels_mut();
+
+        iter.enumerate().for_each(|(i, pixel)| {
+            let (x0, x1, wx) = neighbour_tiles((i as u32 % width) as f32, tile_width, self.columns);
+            let (y0, y1, wy) = neighbour_tiles((i as u32 / width) as f32, tile_height, self.rows);
+
+            for channel in &mut pixel.0[..3] {
+                let value = |x: u32, y: u32| {
+                    self.luts[(y * self.columns + x) as usize][*channel as usize] as f32
+                };
+
+                let top = value(x0, y0) * (1.0 - wx) + value(x1, y0) * wx;
+                let bottom = value(x0, y1) * (1.0 - wx) + value(x1, y1) * wx;
+
+                *channel = (top * (1.0 - wy) + bottom * wy).round() as u8;
+            }
+        });
+
+        img.into()
+    }
+}
+
+/// Curve keeping lightness unchanged
+const IDENTITY_LUT: [u8; 256] = {
+    let mut lut = [0; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        lut[i] = i as u8;
+        i += 1;
+    }
+
+    lut
+};
+
+/// Tiles around pixel and weight of the second one, pixels between centers of tiles are blended
+fn neighbour_tiles(position: f32, tile_size: f32, tiles: u32) -> (u32, u32, f32) {
+    let position = ((position + 0.5) / tile_size - 0.5).clamp(0.0, (tiles - 1) as f32);
+    let first = position.floor() as u32;
+
+    (first, (first + 1).min(tiles - 1), position.fract())
+}
+
+/// Stretch the range of present values into the full one
+fn auto_levels_lut(bins: &[u32; 256]) -> [u8; 256] {
+    let min = bins.iter().position(|count| *count > 0);
+    let max = bins.iter().rposition(|count| *count > 0);
+
+    match (min, max) {
+        (Some(min), Some(max)) if min < max => std::array::from_fn(|i| {
+            ((i.clamp(min, max) - min) as f32 * 255.0 / (max - min) as f32).round() as u8
+        }),
+        _ => IDENTITY_LUT,
+    }
+}
+
+/// Map values by their cumulative share of pixels
+fn equalize_lut(bins: &[u32; 256]) -> [u8; 256] {
+    let total = bins.iter().sum::<u32>();
+    let first = bins
+        .iter()
+        .copied()
+        .find(|count| *count > 0)
+        .unwrap_or_default();
+
+    if total == first {
+        return IDENTITY_LUT;
+    }
+
+    let mut cumulative = 0;
+
+    bins.map(|count| {
+        cumulative += count;
+        (cumulative.saturating_sub(first) as f32 * 255.0 / (total - first) as f32).round() as u8
+    })
+}
+
+/// Clip bins higher than limit, and spread their excess evenly
+fn clip_histogram(bins: &[u32; 256], clip_limit: f32) -> [u32; 256] {
+    let total = bins.iter().sum::<u32>();
+    let limit = ((clip_limit * total as f32 / 256.0) as u32).max(1);
+
+    let excess = bins
+        .iter()
+        .map(|count| count.saturating_sub(limit))
+        .sum::<u32>();
+    let (spread, remainder) = (excess / 256, (excess % 256) as usize);
+
+    let mut i = 0;
+
+    bins.map(|count| {
+        i += 1;
+        count.min(limit) + spread + (i <= remainder) as u32
+    })
+}
@@ -0,0 +1,355 @@
+//! Utils for cropping, rotating and flipping images before resizing
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use image::imageops::FilterType;
+//! use tapciify::{
+//!     prelude::*,
+//!     utils::{
+//!         resize::DEFAULT_FONT_RATIO,
+//!         transform::{Rotation, Transform, open_oriented},
+//!     },
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = open_oriented("./assets/examples/ferris.webp")?;
+//!
+//! let transform = Transform {
+//!     crop: Some("50%x100%".parse()?),
+//!     rotation: Rotation::Clockwise90,
+//!     ..Default::default()
+//! };
+//!
+//! let result = transform
+//!     .apply(img)
+//!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+//!     .ascii_art(&AsciiArtConverterOptions::default())?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error, fmt, path::Path, str::FromStr};
+
+use image::{ImageDecoder, ImageReader};
+
+/// Open image, rotated and flipped by its EXIF orientation, like phone photos
+pub fn open_oriented<P: AsRef<Path>>(path: P) -> image::ImageResult<image::DynamicImage> {
+    let mut decoder = ImageReader::open(path)?
+        .with_guessed_format()?
+        .into_decoder()?;
+    let orientation = decoder.orientation()?;
+
+    let mut img = image::DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+
+    Ok(img)
+}
+
+/// Length in pixels, or in percents of image size
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// Pixels of image
+    Pixels(u32),
+    /// Percents of image size
+    Percent(f32),
+}
+
+impl Length {
+    /// Length in pixels for image size
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::transform::Length;
+    ///
+    /// assert_eq!(Length::Pixels(30).resolve(200), 30);
+    /// assert_eq!(Length::Percent(25.0).resolve(200), 50);
+    /// ```
+    pub fn resolve(&self, size: u32) -> u32 {
+        match *self {
+            Length::Pixels(pixels) => pixels,
+            Length::Percent(percent) => (size as f32 * percent / 100.0).round() as u32,
+        }
+    }
+}
+
+impl FromStr for Length {
+    type Err = TransformError;
+
+    /// Parse pixels, like `120`, or percents, like `50%`
+    fn from_str(s: &str) -> Result<Length, TransformError> {
+        let invalid = || TransformError(s.to_owned());
+
+        match s.trim().strip_suffix('%') {
+            Some(percent) => percent
+                .parse::<f32>()
+                .ok()
+                .filter(|percent| percent.is_finite() && *percent >= 0.0)
+                .map(Length::Percent)
+                .ok_or_else(invalid),
+            None => s.trim().parse().map(Length::Pixels).map_err(|_| invalid()),
+        }
+    }
+}
+
+/// Rectangle of image to keep
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CropRect {
+    /// Left edge
+    pub x: Length,
+    /// Top edge
+    pub y: Length,
+    /// Width
+    pub width: Length,
+    /// Height
+    pub height: Length,
+}
+
+impl CropRect {
+    /// Position and size in pixels, clamped to image size and at least 1 pixel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::transform::CropRect;
+    ///
+    /// # fn main() -> Result<(), tapciify::utils::transform::TransformError> {
+    /// let crop = "50%x50%+25%+25%".parse::<CropRect>()?;
+    /// assert_eq!(crop.resolve(200, 100), (50, 25, 100, 50));
+    ///
+    /// let crop = "100x100+150+0".parse::<CropRect>()?;
+    /// assert_eq!(crop.resolve(200, 100), (150, 0, 50, 100));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve(&self, width: u32, height: u32) -> (u32, u32, u32, u32) {
+        let x = self.x.resolve(width).min(width.saturating_sub(1));
+        let y = self.y.resolve(height).min(height.saturating_sub(1));
+
+        (
+            x,
+            y,
+            self.width.resolve(width).min(width - x).max(1),
+            self.height.resolve(height).min(height - y).max(1),
+        )
+    }
+}
+
+impl FromStr for CropRect {
+    type Err = TransformError;
+
+    /// Parse `<width>x<height>+<x>+<y>` or `<width>x<height>`, every length is in pixels
+    /// or percents
+    fn from_str(s: &str) -> Result<CropRect, TransformError> {
+        let invalid = || TransformError(s.to_owned());
+
+        let mut parts = s.split('+');
+        let (width, height) = parts
+            .next()
+            .and_then(|size| size.split_once('x'))
+            .ok_or_else(invalid)?;
+
+        let offsets = parts.map(str::parse).collect::<Result<Vec<Length>, _>>()?;
+        let (x, y) = match offsets[..] {
+            [] => (Length::Pixels(0), Length::Pixels(0)),
+            [x, y] => (x, y),
+            _ => return Err(invalid()),
+        };
+
+        Ok(CropRect {
+            x,
+            y,
+            width: width.parse()?,
+            height: height.parse()?,
+        })
+    }
+}
+
+/// Zoom into region around center
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Zoom {
+    /// Magnification, at least 1.0
+    pub factor: f32,
+    /// Horizontal center of region
+    pub x: Length,
+    /// Vertical center of region
+    pub y: Length,
+}
+
+impl Zoom {
+    /// Region of image to keep, same as [`CropRect`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::transform::Zoom;
+    ///
+    /// # fn main() -> Result<(), tapciify::utils::transform::TransformError> {
+    /// let zoom = "2".parse::<Zoom>()?;
+    /// assert_eq!(zoom.resolve(200, 100), (50, 25, 100, 50));
+    ///
+    /// // Region near the edge is moved inside image
+    /// let zoom = "4@0,100%".parse::<Zoom>()?;
+    /// assert_eq!(zoom.resolve(200, 100), (0, 75, 50, 25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resolve(&self, width: u32, height: u32) -> (u32, u32, u32, u32) {
+        let factor = self.factor.max(1.0);
+
+        let region = |size: u32, center: Length| {
+            let region_size = ((size as f32 / factor).round() as u32).clamp(1, size.max(1));
+            let start = center.resolve(size).saturating_sub(region_size / 2);
+
+            (start.min(size.saturating_sub(region_size)), region_size)
+        };
+
+        let (x, region_width) = region(width, self.x);
+        let (y, region_height) = region(height, self.y);
+
+        (x, y, region_width, region_height)
+    }
+}
+
+impl FromStr for Zoom {
+    type Err = TransformError;
+
+    /// Parse `<factor>` to zoom into center, or `<factor>@<x>,<y>`, where center is in
+    /// pixels or percents
+    fn from_str(s: &str) -> Result<Zoom, TransformError> {
+        let invalid = || TransformError(s.to_owned());
+
+        let (factor, center) = match s.split_once('@') {
+            Some((factor, center)) => (factor, Some(center)),
+            None => (s, None),
+        };
+
+        let factor = factor
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|factor| factor.is_finite() && *factor >= 1.0)
+            .ok_or_else(invalid)?;
+
+        let (x, y) = match center {
+            Some(center) => {
+                let (x, y) = center.split_once(',').ok_or_else(invalid)?;
+                (x.parse()?, y.parse()?)
+            }
+            None => (Length::Percent(50.0), Length::Percent(50.0)),
+        };
+
+        Ok(Zoom { factor, x, y })
+    }
+}
+
+/// Clockwise rotation of image
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum Rotation {
+    /// Keep image as is
+    #[default]
+    #[cfg_attr(feature = "player", value(name = "0"))]
+    None,
+    /// Rotate by 90 degrees clockwise
+    #[cfg_attr(feature = "player", value(name = "90"))]
+    Clockwise90,
+    /// Rotate by 180 degrees
+    #[cfg_attr(feature = "player", value(name = "180"))]
+    Clockwise180,
+    /// Rotate by 270 degrees clockwise
+    #[cfg_attr(feature = "player", value(name = "270"))]
+    Clockwise270,
+}
+
+/// Geometric transforms, applied in order of fields
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// Keep only rectangle of image
+    pub crop: Option<CropRect>,
+    /// Zoom into region of cropped image
+    pub zoom: Option<Zoom>,
+    /// Rotate image clockwise
+    pub rotation: Rotation,
+    /// Mirror image from left to right
+    pub flip_horizontal: bool,
+    /// Mirror image from top to bottom
+    pub flip_vertical: bool,
+}
+
+impl Transform {
+    /// Apply transforms to image
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use image::{DynamicImage, GrayImage, Luma};
+    /// use tapciify::utils::transform::{Rotation, Transform};
+    ///
+    /// # fn main() -> Result<(), tapciify::utils::transform::TransformError> {
+    /// let img = DynamicImage::from(GrayImage::from_fn(4, 2, |x, y| Luma([(y * 4 + x) as u8])));
+    ///
+    /// let result = Transform {
+    ///     crop: Some("2x2+1+0".parse()?),
+    ///     rotation: Rotation::Clockwise90,
+    ///     flip_vertical: true,
+    ///     ..Default::default()
+    /// }
+    /// .apply(img);
+    ///
+    /// assert_eq!(result.to_luma8().into_raw(), [6, 2, 5, 1]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn apply(&self, img: image::DynamicImage) -> image::DynamicImage {
+        if img.width() == 0 || img.height() == 0 {
+            return img;
+        }
+
+        let mut img = match self.crop {
+            Some(crop) => {
+                let (x, y, width, height) = crop.resolve(img.width(), img.height());
+                img.crop_imm(x, y, width, height)
+            }
+            None => img,
+        };
+
+        if let Some(zoom) = self.zoom {
+            let (x, y, width, height) = zoom.resolve(img.width(), img.height());
+            img = img.crop_imm(x, y, width, height);
+        }
+
+        img = match self.rotation {
+            Rotation::None => img,
+            Rotation::Clockwise90 => img.rotate90(),
+            Rotation::Clockwise180 => img.rotate180(),
+            Rotation::Clockwise270 => img.rotate270(),
+        };
+
+        if self.flip_horizontal {
+            img = img.fliph();
+        }
+        if self.flip_vertical {
+            img = img.flipv();
+        }
+
+        img
+    }
+}
+
+/// Error caused by invalid [`Length`], [`CropRect`] or [`Zoom`] string
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TransformError(pub String);
+
+impl error::Error for TransformError {}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid transform `{}`", self.0)
+    }
+}
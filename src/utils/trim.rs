@@ -0,0 +1,209 @@
+//! Utils for removing uniform borders, so width of ASCII art is spent on the subject
+//!
+//! # Examples
+//!
+//! ```
+//! use std::error::Error;
+//!
+//! use image::imageops::FilterType;
+//! use tapciify::{
+//!     prelude::*,
+//!     utils::{
+//!         resize::DEFAULT_FONT_RATIO,
+//!         trim::{DEFAULT_TRIM_TOLERANCE, trim_borders},
+//!     },
+//! };
+//!
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let img = image::open("./assets/examples/ferris.webp")?;
+//!
+//! let result = trim_borders(img, DEFAULT_TRIM_TOLERANCE)
+//!     .resize_custom_ratio(Some(64), None, DEFAULT_FONT_RATIO, FilterType::Triangle)
+//!     .ascii_art(&AsciiArtConverterOptions::default())?;
+//!
+//! println!("{}", result);
+//! # Ok(())
+//! # }
+//! ```
+
+/// Default difference of channels (from 0.0 to 1.0) from border color, still counted as border
+pub const DEFAULT_TRIM_TOLERANCE: f32 = 0.05;
+
+/// Frames used for finding borders
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "player", derive(clap::ValueEnum))]
+pub enum TrimScope {
+    /// Find borders of every frame
+    #[default]
+    Frame,
+    /// Find borders containing subject of all frames, so animations don't jitter
+    Sequence,
+}
+
+/// Rectangle of image in pixels
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    /// Left edge
+    pub x: u32,
+    /// Top edge
+    pub y: u32,
+    /// Width
+    pub width: u32,
+    /// Height
+    pub height: u32,
+}
+
+impl Bounds {
+    /// Smallest rectangle containing both rectangles
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tapciify::utils::trim::Bounds;
+    ///
+    /// let a = Bounds { x: 2, y: 0, width: 2, height: 1 };
+    /// let b = Bounds { x: 0, y: 3, width: 1, height: 1 };
+    ///
+    /// assert_eq!(a.union(&b), Bounds { x: 0, y: 0, width: 4, height: 4 });
+    /// ```
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Bounds {
+            x,
+            y,
+            width: (self.x + self.width).max(other.x + other.width) - x,
+            height: (self.y + self.height).max(other.y + other.height) - y,
+        }
+    }
+
+    /// Crop image to rectangle, clamped to image size
+    pub fn crop(&self, img: image::DynamicImage) -> image::DynamicImage {
+        if img.width() == 0 || img.height() == 0 {
+            return img;
+        }
+
+        let x = self.x.min(img.width() - 1);
+        let y = self.y.min(img.height() - 1);
+
+        img.crop_imm(
+            x,
+            y,
+            self.width.min(img.width() - x).max(1),
+            self.height.min(img.height() - y).max(1),
+        )
+    }
+}
+
+/// Find rectangle of pixels, which differ from border color more than tolerance
+///
+/// Border color is the color of corners, shared by most of them, so subject can touch
+/// some of the corners. When every corner differs, there are no borders. Transparent
+/// borders are compared only by alpha. Images made only of border have no content
+///
+/// # Examples
+///
+/// ```
+/// use image::{DynamicImage, Rgb, RgbImage};
+/// use tapciify::utils::trim::{Bounds, content_bounds};
+///
+/// let img = RgbImage::from_fn(5, 4, |x, y| match (x, y) {
+///     (1..=2, 2) => Rgb([0, 0, 0]),
+///     (4, 0) => Rgb([250, 250, 250]),
+///     _ => Rgb([255, 255, 255]),
+/// });
+/// let img = DynamicImage::from(img);
+///
+/// assert_eq!(
+///     content_bounds(&img, 0.05),
+///     Some(Bounds { x: 1, y: 2, width: 2, height: 1 })
+/// );
+/// assert_eq!(
+///     content_bounds(&img, 0.0),
+///     Some(Bounds { x: 1, y: 0, width: 4, height: 3 })
+/// );
+/// ```
+///
+/// Subject touching the top left corner keeps the other sides trimmed:
+///
+/// ```
+/// use image::{DynamicImage, Rgb, RgbImage};
+/// use tapciify::utils::trim::{Bounds, content_bounds};
+///
+/// let img = RgbImage::from_fn(5, 4, |x, y| match (x, y) {
+///     (0..=2, 0..=1) => Rgb([0, 0, 0]),
+///     _ => Rgb([255, 255, 255]),
+/// });
+/// let img = DynamicImage::from(img);
+///
+/// assert_eq!(
+///     content_bounds(&img, 0.05),
+///     Some(Bounds { x: 0, y: 0, width: 3, height: 2 })
+/// );
+/// ```
+pub fn content_bounds(img: &image::DynamicImage, tolerance: f32) -> Option<Bounds> {
+    let img = img.to_rgba8();
+    let (width, height) = img.dimensions();
+    let tolerance = (tolerance.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let is_border = |border: &image::Rgba<u8>, pixel: &image::Rgba<u8>| match border[3] {
+        0 => pixel[3] <= tolerance,
+        _ => (0..4).all(|i| pixel[i].abs_diff(border[i]) <= tolerance),
+    };
+
+    // Corner matching most of the corners, the first one wins ties
+    let corners = [
+        (0, 0),
+        (width - 1, 0),
+        (0, height - 1),
+        (width - 1, height - 1),
+    ]
+    .map(|(x, y)| *img.get_pixel(x, y));
+    let (matches, border) = corners
+        .iter()
+        .map(|border| {
+            let matches = corners.iter().filter(|corner| is_border(border, corner));
+            (matches.count(), border)
+        })
+        .rev()
+        .max_by_key(|(matches, _)| *matches)?;
+
+    if matches < 2 {
+        return Some(Bounds {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        });
+    }
+
+    let (min_x, min_y, max_x, max_y) = img
+        .enumerate_pixels()
+        .filter(|(_, _, pixel)| !is_border(border, pixel))
+        .fold(None, |bounds, (x, y, _)| match bounds {
+            None => Some((x, y, x, y)),
+            Some((min_x, min_y, max_x, max_y)) => {
+                Some((min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)))
+            }
+        })?;
+
+    Some(Bounds {
+        x: min_x,
+        y: min_y,
+        width: max_x - min_x + 1,
+        height: max_y - min_y + 1,
+    })
+}
+
+/// Remove uniform borders of image, images made only of border are kept as is
+pub fn trim_borders(img: image::DynamicImage, tolerance: f32) -> image::DynamicImage {
+    match content_bounds(&img, tolerance) {
+        Some(bounds) => bounds.crop(img),
+        None => img,
+    }
+}